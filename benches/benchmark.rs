@@ -5,9 +5,8 @@
 
 use criterion::{criterion_group, criterion_main, Criterion};
 
-use casbab;
-
 const BENCHMARK_PHASE: &str = "xCAMELSnakeKebab_screaming pascal XXX";
+const BENCHMARK_PHASE_UNICODE: &str = "xЦАМЕЛSnakeКебаб_screaming паскал XXX";
 
 fn benchmark_camel(c: &mut Criterion) {
     c.bench_function("camel", |b| {
@@ -75,6 +74,112 @@ fn benchmark_screaming(c: &mut Criterion) {
     });
 }
 
+fn benchmark_camel_unicode(c: &mut Criterion) {
+    c.bench_function("camel_unicode", |b| {
+        b.iter(|| casbab::camel(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_pascal_unicode(c: &mut Criterion) {
+    c.bench_function("pascal_unicode", |b| {
+        b.iter(|| casbab::pascal(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_snake_unicode(c: &mut Criterion) {
+    c.bench_function("snake_unicode", |b| {
+        b.iter(|| casbab::snake(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_camel_snake_unicode(c: &mut Criterion) {
+    c.bench_function("camel_snake_unicode", |b| {
+        b.iter(|| casbab::camel_snake(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_screaming_snake_unicode(c: &mut Criterion) {
+    c.bench_function("screaming_snake_unicode", |b| {
+        b.iter(|| casbab::screaming_snake(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_kebab_unicode(c: &mut Criterion) {
+    c.bench_function("kebab_unicode", |b| {
+        b.iter(|| casbab::kebab(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_camel_kebab_unicode(c: &mut Criterion) {
+    c.bench_function("camel_kebab_unicode", |b| {
+        b.iter(|| casbab::camel_kebab(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_screaming_kebab_unicode(c: &mut Criterion) {
+    c.bench_function("screaming_kebab_unicode", |b| {
+        b.iter(|| casbab::screaming_kebab(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_lower_unicode(c: &mut Criterion) {
+    c.bench_function("lower_unicode", |b| {
+        b.iter(|| casbab::lower(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_title_unicode(c: &mut Criterion) {
+    c.bench_function("title_unicode", |b| {
+        b.iter(|| casbab::title(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn benchmark_screaming_unicode(c: &mut Criterion) {
+    c.bench_function("screaming_unicode", |b| {
+        b.iter(|| casbab::screaming(BENCHMARK_PHASE_UNICODE));
+    });
+}
+
+fn batch() -> Vec<String> {
+    (0..10_000)
+        .map(|i| format!("{BENCHMARK_PHASE} {i}"))
+        .collect()
+}
+
+fn benchmark_convert_each(c: &mut Criterion) {
+    let batch = batch();
+    c.bench_function("convert_each", |b| {
+        b.iter(|| {
+            batch
+                .iter()
+                .map(|s| casbab::Case::Snake.convert(s))
+                .collect::<Vec<_>>()
+        });
+    });
+}
+
+fn benchmark_convert_many(c: &mut Criterion) {
+    let batch = batch();
+    let phrases: Vec<&str> = batch.iter().map(String::as_str).collect();
+    c.bench_function("convert_many", |b| {
+        b.iter(|| casbab::convert_many(casbab::Case::Snake, &phrases));
+    });
+}
+
+fn benchmark_convert_many_into(c: &mut Criterion) {
+    let batch = batch();
+    let phrases: Vec<&str> = batch.iter().map(String::as_str).collect();
+    let mut buf = String::new();
+    let mut offsets = Vec::new();
+    c.bench_function("convert_many_into", |b| {
+        b.iter(|| {
+            buf.clear();
+            offsets.clear();
+            casbab::convert_many_into(casbab::Case::Snake, &phrases, &mut buf, &mut offsets);
+        });
+    });
+}
+
 criterion_group!(
     benches,
     benchmark_camel,
@@ -88,5 +193,19 @@ criterion_group!(
     benchmark_lower,
     benchmark_title,
     benchmark_screaming,
+    benchmark_camel_unicode,
+    benchmark_pascal_unicode,
+    benchmark_snake_unicode,
+    benchmark_camel_snake_unicode,
+    benchmark_screaming_snake_unicode,
+    benchmark_kebab_unicode,
+    benchmark_camel_kebab_unicode,
+    benchmark_screaming_kebab_unicode,
+    benchmark_lower_unicode,
+    benchmark_title_unicode,
+    benchmark_screaming_unicode,
+    benchmark_convert_each,
+    benchmark_convert_many,
+    benchmark_convert_many_into,
 );
 criterion_main!(benches);
@@ -0,0 +1,203 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! # Camel Snake Kebab derive
+//!
+//! Derive macro `CaseNames` generates names of enum variants and struct
+//! fields converted with the *casbab* library at compile time, so that
+//! they are always the same as the ones returned by the library
+//! functions at runtime.
+//!
+//! The case is selected with the `casbab` attribute using the name of
+//! the library function: `camel`, `pascal`, `snake`, `camel_snake`,
+//! `screaming_snake`, `kebab`, `camel_kebab`, `screaming_kebab`, `lower`,
+//! `title` or `screaming`.
+//!
+//! For enums with unit variants, it generates:
+//!
+//! - `NAMES` constant with the names of all variants
+//! - `as_str` const method that returns the name of the variant
+//! - `Display` implementation that writes the name of the variant
+//! - `FromStr` implementation that parses the name of the variant
+//!   returning `casbab::UnknownNameError` if no variant matches
+//!
+//! For structs with named fields, it generates the `FIELD_NAMES`
+//! constant with the names of all fields.
+//!
+//! Two variants or fields that are converted into the same name are a
+//! compile error.
+//!
+//! The generated code refers to the `casbab` crate, which must be a
+//! dependency of the crate that uses the macro.
+//!
+//! ```
+//! use casbab_derive::CaseNames;
+//!
+//! #[derive(CaseNames)]
+//! #[casbab(kebab)]
+//! enum Command {
+//!     HttpGet,
+//!     HTTPPost,
+//! }
+//!
+//! assert_eq!(Command::NAMES, ["http-get", "http-post"]);
+//! assert_eq!(Command::HTTPPost.as_str(), "http-post");
+//! assert!(matches!("http-get".parse(), Ok(Command::HttpGet)));
+//! ```
+
+use casbab::Case;
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident};
+
+/// Generates names converted into the case selected by the `casbab`
+/// attribute. See the crate documentation for details.
+#[proc_macro_derive(CaseNames, attributes(casbab))]
+pub fn derive_case_names(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let case = case(&input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    match &input.data {
+        Data::Enum(data) => {
+            let mut variants = Vec::new();
+            let mut names = Vec::new();
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new_spanned(
+                        variant,
+                        "CaseNames supports only unit enum variants",
+                    ));
+                }
+                let name = convert(case, &variant.ident);
+                if let Some(i) = names.iter().position(|n| *n == name) {
+                    return Err(Error::new_spanned(
+                        &variant.ident,
+                        format!(
+                            "variants `{}` and `{}` have the same name `{name}`",
+                            variants[i], variant.ident
+                        ),
+                    ));
+                }
+                variants.push(&variant.ident);
+                names.push(name);
+            }
+            Ok(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Names of all variants.
+                    pub const NAMES: &'static [&'static str] = &[#(#names),*];
+
+                    /// Returns the name of the variant.
+                    pub const fn as_str(&self) -> &'static str {
+                        match *self {
+                            #(Self::#variants => #names,)*
+                        }
+                    }
+                }
+
+                impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str(self.as_str())
+                    }
+                }
+
+                impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+                    type Err = ::casbab::UnknownNameError;
+
+                    fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                        match s {
+                            #(#names => ::core::result::Result::Ok(Self::#variants),)*
+                            _ => ::core::result::Result::Err(::casbab::UnknownNameError::new(s)),
+                        }
+                    }
+                }
+            })
+        }
+        Data::Struct(data) => {
+            let Fields::Named(fields) = &data.fields else {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "CaseNames supports only structs with named fields",
+                ));
+            };
+            let mut idents: Vec<&Ident> = Vec::new();
+            let mut names = Vec::new();
+            for ident in fields.named.iter().filter_map(|field| field.ident.as_ref()) {
+                let name = convert(case, ident);
+                if let Some(i) = names.iter().position(|n| *n == name) {
+                    return Err(Error::new_spanned(
+                        ident,
+                        format!(
+                            "fields `{}` and `{ident}` have the same name `{name}`",
+                            idents[i]
+                        ),
+                    ));
+                }
+                idents.push(ident);
+                names.push(name);
+            }
+            Ok(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Names of all fields.
+                    pub const FIELD_NAMES: &'static [&'static str] = &[#(#names),*];
+                }
+            })
+        }
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "CaseNames does not support unions",
+        )),
+    }
+}
+
+/// Returns the case from the `casbab` attribute.
+fn case(input: &DeriveInput) -> Result<Case, Error> {
+    let mut case = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("casbab")) {
+        attr.parse_nested_meta(|meta| {
+            let Some(ident) = meta.path.get_ident() else {
+                return Err(meta.error("expected case name"));
+            };
+            if case.is_some() {
+                return Err(meta.error("case is already set"));
+            }
+            case = Some(match ident.to_string().as_str() {
+                "camel" => Case::Camel,
+                "pascal" => Case::Pascal,
+                "snake" => Case::Snake,
+                "camel_snake" => Case::CamelSnake,
+                "screaming_snake" => Case::ScreamingSnake,
+                "kebab" => Case::Kebab,
+                "camel_kebab" => Case::CamelKebab,
+                "screaming_kebab" => Case::ScreamingKebab,
+                "lower" => Case::Lower,
+                "title" => Case::Title,
+                "screaming" => Case::Screaming,
+                _ => return Err(meta.error(format!("unknown case `{ident}`"))),
+            });
+            Ok(())
+        })?;
+    }
+    case.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "missing case attribute, for example #[casbab(kebab)]",
+        )
+    })
+}
+
+/// Converts the identifier without the raw identifier prefix.
+fn convert(case: Case, ident: &Ident) -> String {
+    let ident = ident.to_string();
+    case.convert(ident.strip_prefix("r#").unwrap_or(&ident))
+}
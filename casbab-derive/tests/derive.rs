@@ -0,0 +1,67 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use casbab_derive::CaseNames;
+
+#[derive(Debug, PartialEq, CaseNames)]
+#[casbab(screaming_snake)]
+enum Level {
+    Debug,
+    WarningOrError,
+    HTTPError,
+}
+
+#[derive(Debug, PartialEq, CaseNames)]
+#[casbab(kebab)]
+#[allow(dead_code, non_camel_case_types)]
+enum Command {
+    Get,
+    http_post,
+    r#Type,
+}
+
+#[derive(CaseNames)]
+#[casbab(camel)]
+#[allow(dead_code)]
+struct Config {
+    listen_address: String,
+    max_http_connections: u32,
+    r#type: String,
+}
+
+#[test]
+fn enum_test() {
+    assert_eq!(Level::NAMES, ["DEBUG", "WARNING_OR_ERROR", "HTTP_ERROR"]);
+    assert_eq!(
+        Level::HTTPError.as_str(),
+        casbab::screaming_snake("HTTPError")
+    );
+    assert_eq!(Level::WarningOrError.to_string(), "WARNING_OR_ERROR");
+    assert_eq!("HTTP_ERROR".parse(), Ok(Level::HTTPError));
+    assert_eq!(
+        "HttpError".parse::<Level>(),
+        Err(casbab::UnknownNameError::new("HttpError"))
+    );
+
+    const NAME: &str = Command::http_post.as_str();
+    assert_eq!(NAME, "http-post");
+    assert_eq!(Command::NAMES, ["get", "http-post", "type"]);
+    assert_eq!("type".parse(), Ok(Command::r#Type));
+}
+
+#[test]
+fn struct_test() {
+    assert_eq!(
+        Config::FIELD_NAMES,
+        ["listenAddress", "maxHttpConnections", "type"]
+    );
+}
+
+#[test]
+fn ui_test() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/empty_enum.rs");
+    t.compile_fail("tests/ui/duplicate_*.rs");
+}
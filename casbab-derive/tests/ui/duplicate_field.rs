@@ -0,0 +1,16 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use casbab_derive::CaseNames;
+
+#[derive(CaseNames)]
+#[casbab(kebab)]
+#[allow(non_snake_case)]
+struct Config {
+    max_size: u32,
+    maxSize: u32,
+}
+
+fn main() {}
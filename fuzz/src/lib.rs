@@ -0,0 +1,66 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Checks shared by fuzz targets. They panic if any of the invariants
+//! does not hold for the input.
+
+use casbab::{reversible, Case};
+
+/// Checks the conversion of the input by the converter function and by
+/// all other APIs for the same case.
+pub fn check_convert(case: Case, convert: fn(&str) -> String, s: &str) {
+    let converted = convert(s);
+    assert_eq!(case.convert(s), converted);
+    assert_eq!(case.display(s).to_string(), converted);
+
+    let mut w = String::from("prefix");
+    case.write(&mut w, s).unwrap();
+    assert_eq!(&w["prefix".len()..], converted);
+
+    if let Some(separator) = separator(case) {
+        let double: String = [separator, separator].iter().collect();
+        assert!(!converted.trim_matches(separator).contains(&double));
+    }
+
+    let (reversible, hint) = reversible::convert(case, s);
+    assert_eq!(reversible, converted);
+    assert_eq!(
+        reversible::restore(case, &reversible, &hint).as_deref(),
+        Ok(s)
+    );
+}
+
+/// Checks that words are non-empty substrings of the input, found in
+/// order, that start and end on character boundaries, and that only
+/// separators are between them.
+pub fn check_words(s: &str) {
+    let mut end = 0;
+    for w in casbab::words(s) {
+        assert!(!w.is_empty());
+        let start = w.as_ptr() as usize - s.as_ptr() as usize;
+        assert!(start >= end && start + w.len() <= s.len());
+        assert!(s.is_char_boundary(start) && s.is_char_boundary(start + w.len()));
+        assert!(s[end..start].chars().all(is_separator));
+        assert!(!w.chars().any(is_separator));
+        end = start + w.len();
+    }
+    assert!(s[end..].chars().all(is_separator));
+
+    let lower: Vec<String> = casbab::words(s).map(|w| Case::Lower.convert(w)).collect();
+    assert_eq!(lower.join(" "), Case::Lower.convert(s));
+}
+
+fn is_separator(c: char) -> bool {
+    c == '-' || c == '_' || c == ' '
+}
+
+fn separator(case: Case) -> Option<char> {
+    match case {
+        Case::Camel | Case::Pascal => None,
+        Case::Snake | Case::CamelSnake | Case::ScreamingSnake => Some('_'),
+        Case::Kebab | Case::CamelKebab | Case::ScreamingKebab => Some('-'),
+        Case::Lower | Case::Title | Case::Screaming => Some(' '),
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion of batches of ASCII phrases.
+//!
+//! Bytes of a phrase are classified eight at a time as SWAR (SIMD within a
+//! register) operations on `u64` words, into bit masks of upper case
+//! letters and separators with one bit for every byte. Word boundaries are
+//! then found with shifts of the masks, by the same rules as in
+//! `word_bounds`, instead of by a branch for every byte. This is portable
+//! and it does not depend on target features.
+
+use crate::{
+    lit::{spec, Layout, Transform},
+    Case,
+};
+use alloc::{string::String, vec::Vec};
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGH: u64 = 0x8080_8080_8080_8080;
+
+/// Reusable bit masks of a phrase, one bit for every byte.
+#[derive(Default)]
+pub(crate) struct Masks {
+    upper: Vec<u64>,
+    separator: Vec<u64>,
+}
+
+/// Appends the ASCII phrase converted into the case to the buffer.
+pub(crate) fn write(case: Case, s: &str, buf: &mut String, masks: &mut Masks) {
+    let b = s.as_bytes();
+    masks.classify(b);
+    let (layout, transform_first_word, transform) = spec(case);
+
+    let (head, tail) = match layout {
+        Layout::Wrap(separator) => head_tail_count(b, separator),
+        _ => (0, 0),
+    };
+    for _ in 0..head {
+        buf.push(b[0] as char);
+    }
+
+    let mut first = true;
+    masks.for_each_word(b.len(), |start, end| {
+        let transform = if first {
+            transform_first_word
+        } else {
+            if let Layout::Separate(separator) | Layout::Wrap(separator) = layout {
+                buf.push(separator as char);
+            }
+            transform
+        };
+        first = false;
+        let from = buf.len();
+        buf.push_str(&s[start..end]);
+        let word = &mut buf[from..];
+        match transform {
+            Transform::Lower => word.make_ascii_lowercase(),
+            Transform::Upper => word.make_ascii_uppercase(),
+            Transform::Title => {
+                word.make_ascii_lowercase();
+                word[..1].make_ascii_uppercase();
+            }
+        }
+    });
+
+    for _ in 0..tail {
+        buf.push(b[b.len() - 1] as char);
+    }
+}
+
+impl Masks {
+    /// Sets masks of upper case letters and separators for the ASCII bytes.
+    fn classify(&mut self, b: &[u8]) {
+        self.upper.clear();
+        self.separator.clear();
+        for block in b.chunks(64) {
+            let (mut upper, mut separator) = (0, 0);
+            for (i, chunk) in block.chunks(8).enumerate() {
+                let mut word = [0; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                let x = u64::from_le_bytes(word);
+                let u = (x + (0x80 - u64::from(b'A')) * ONES)
+                    & !(x + (0x80 - u64::from(b'Z') - 1) * ONES)
+                    & HIGH;
+                let s = (equal(x, b'-') | equal(x, b'_') | equal(x, b' ')) & HIGH;
+                upper |= movemask(u) << (i * 8);
+                separator |= movemask(s) << (i * 8);
+            }
+            self.upper.push(upper);
+            self.separator.push(separator);
+        }
+    }
+
+    /// Calls the function with the start and the end index of every word
+    /// of the phrase with the length.
+    fn for_each_word(&self, len: usize, mut f: impl FnMut(usize, usize)) {
+        let blocks = self.upper.len();
+        let mut word_start = None;
+        for k in 0..blocks {
+            let valid = if k + 1 == blocks && !len.is_multiple_of(64) {
+                (1 << (len % 64)) - 1
+            } else {
+                u64::MAX
+            };
+            let upper = self.upper[k];
+            let separator = self.separator[k];
+            let lower = !upper & !separator & valid;
+            let (prev_upper, prev_separator, prev_lower) = match k {
+                0 => (0, 1, 0),
+                _ => {
+                    let u = self.upper[k - 1] >> 63;
+                    let s = self.separator[k - 1] >> 63;
+                    (u, s, !u & !s & 1)
+                }
+            };
+            let next_lower = match (self.upper.get(k + 1), self.separator.get(k + 1)) {
+                (Some(u), Some(s)) => !u & !s & 1,
+                _ => 0,
+            };
+
+            // Words start after separators, at upper case letters after
+            // lower case ones, and at the last upper case letter before a
+            // lower case one in a sequence of upper case letters.
+            let after_separator = (separator << 1) | prev_separator;
+            let after_lower = (lower << 1) | prev_lower;
+            let after_upper = (upper << 1) | prev_upper;
+            let before_lower = (lower >> 1) | (next_lower << 63);
+            let starts = !separator
+                & valid
+                & (after_separator | (upper & after_lower) | (upper & after_upper & before_lower));
+
+            let mut boundaries = starts | separator;
+            while boundaries != 0 {
+                let i = k * 64 + boundaries.trailing_zeros() as usize;
+                if let Some(start) = word_start.take() {
+                    f(start, i);
+                }
+                if starts & (boundaries & boundaries.wrapping_neg()) != 0 {
+                    word_start = Some(i);
+                }
+                boundaries &= boundaries - 1;
+            }
+        }
+        if let Some(start) = word_start {
+            f(start, len);
+        }
+    }
+}
+
+/// Returns the word with the high bit set in every byte of the ASCII word
+/// that is equal to the byte, and with other bits in unspecified state.
+fn equal(x: u64, b: u8) -> u64 {
+    let t = x ^ (u64::from(b) * ONES);
+    !(((t & !HIGH) + !HIGH) | t)
+}
+
+/// Gathers high bits of every byte into the lowest eight bits.
+fn movemask(x: u64) -> u64 {
+    ((x >> 7) & ONES).wrapping_mul(0x0102_0408_1020_4080) >> 56
+}
+
+fn head_tail_count(b: &[u8], sub: u8) -> (usize, usize) {
+    match b.iter().position(|&c| c != sub) {
+        Some(head) => (head, b.iter().rev().position(|&c| c != sub).unwrap_or(0)),
+        None => (0, 0),
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion of a phrase into all dialects.
+
+use crate::{stdout, write_failed};
+use casbab::Case;
+use clap::ArgMatches;
+use std::io::{self, Write};
+
+/// Runs the `all` subcommand.
+pub fn run(matches: &ArgMatches) {
+    let phrase = matches
+        .get_many::<String>("phrase")
+        .expect("`phrase` is required")
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let format = matches.get_one::<String>("format").expect("has default");
+    let mut w = stdout();
+    if let Err(e) = write_all(&mut w, &phrase, format).and_then(|_| w.flush()) {
+        write_failed(e);
+    }
+}
+
+/// Writes the phrase converted into all dialects in the format, which is
+/// one of `table`, `json` or `tsv`.
+pub fn write_all<W: Write>(w: &mut W, phrase: &str, format: &str) -> io::Result<()> {
+    match format {
+        "json" => {
+            writeln!(w, "{{")?;
+            for (i, case) in Case::ALL.iter().enumerate() {
+                let comma = if i + 1 < Case::ALL.len() { "," } else { "" };
+                writeln!(
+                    w,
+                    "  {}: {}{comma}",
+                    json_string(case.name()),
+                    json_string(&case.convert(phrase))
+                )?;
+            }
+            writeln!(w, "}}")
+        }
+        "tsv" => {
+            for case in Case::ALL {
+                writeln!(w, "{}\t{}", case.name(), case.display(phrase))?;
+            }
+            Ok(())
+        }
+        _ => {
+            let width = Case::ALL.iter().map(|c| c.name().len()).max().unwrap_or(0);
+            for case in Case::ALL {
+                writeln!(w, "{:width$}  {}", case.name(), case.display(phrase))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    serde_json::to_string(s).expect("strings are always serialized")
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Generation of shell completion scripts.
+
+use crate::{command, stdout, write_failed};
+use clap::ArgMatches;
+use clap_complete::Shell;
+use std::io::Write;
+
+/// Runs the `completions` subcommand.
+pub fn run(matches: &ArgMatches) {
+    let shell = *matches
+        .get_one::<Shell>("shell")
+        .expect("`shell` is required");
+    // Generators panic on write errors, so the script is written to
+    // Stdout only when it is complete.
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command(), "casbab", &mut script);
+    let mut w = stdout();
+    if let Err(e) = w.write_all(&script).and_then(|_| w.flush()) {
+        write_failed(e);
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion of the header row of CSV documents.
+
+use crate::{dialect::Dialect, fail, stdout, write_failed};
+use casbab::Case;
+use clap::ArgMatches;
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    io::{self, BufRead, BufReader, Write},
+};
+
+/// Runs the `csv-headers` subcommand.
+pub fn run(matches: &ArgMatches) {
+    let Dialect(case) = *matches
+        .get_one::<Dialect>("dialect")
+        .expect("`dialect` is required");
+    let delimiter = *matches.get_one::<char>("delimiter").expect("has default");
+    let quote = *matches.get_one::<char>("quote").expect("has default");
+    let mut r: Box<dyn io::BufRead> = match matches.get_one::<String>("file") {
+        Some(file) => Box::new(BufReader::new(
+            fs::File::open(file).unwrap_or_else(|e| fail(format!("open {file}: {e}"))),
+        )),
+        None => Box::new(io::stdin().lock()),
+    };
+    let mut w = stdout();
+    let duplicates = match convert_headers(&mut r, &mut w, case, delimiter, quote) {
+        Ok(duplicates) => duplicates,
+        Err(Error::Io(e)) => write_failed(e),
+        Err(e) => fail(e.to_string()),
+    };
+    if let Err(e) = w.flush() {
+        write_failed(e);
+    }
+    for duplicate in duplicates {
+        eprintln!("warning: {duplicate}");
+    }
+}
+
+/// Columns at zero based indexes which names are converted to the same
+/// name.
+pub struct Duplicate {
+    pub first: usize,
+    pub second: usize,
+    pub name: String,
+}
+
+impl fmt::Display for Duplicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "columns {} and {} are both converted to `{}`",
+            self.first + 1,
+            self.second + 1,
+            self.name
+        )
+    }
+}
+
+pub enum Error {
+    Io(io::Error),
+    Utf8,
+    UnterminatedQuote,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Utf8 => f.write_str("header row is not valid UTF-8"),
+            Error::UnterminatedQuote => f.write_str("header row has an unterminated quoted field"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+struct Field {
+    value: String,
+    quoted: bool,
+}
+
+/// Converts column names in the header row and copies the rest of the
+/// input unchanged. Fields that were quoted, or that need quoting after
+/// the conversion, are written quoted. Returns columns with duplicate
+/// names after the conversion.
+pub fn convert_headers<R: BufRead, W: Write>(
+    r: &mut R,
+    w: &mut W,
+    case: Case,
+    delimiter: char,
+    quote: char,
+) -> Result<Vec<Duplicate>, Error> {
+    let mut buf = Vec::new();
+    let (fields, terminator) = loop {
+        let eof = r.read_until(b'\n', &mut buf)? == 0;
+        let record = std::str::from_utf8(&buf).map_err(|_| Error::Utf8)?;
+        match parse(record, delimiter, quote) {
+            Some(parsed) => break parsed,
+            None if eof => return Err(Error::UnterminatedQuote),
+            None => {}
+        }
+    };
+    if fields.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let bom = fields[0].value.starts_with('\u{feff}');
+    if bom {
+        w.write_all("\u{feff}".as_bytes())?;
+    }
+
+    let mut duplicates = Vec::new();
+    let mut names: HashMap<String, usize> = HashMap::new();
+    for (i, field) in fields.iter().enumerate() {
+        let value = match i {
+            0 if bom => &field.value['\u{feff}'.len_utf8()..],
+            _ => &field.value,
+        };
+        let name = case.convert(value);
+        if i > 0 {
+            write!(w, "{delimiter}")?;
+        }
+        if field.quoted || name.contains([delimiter, quote, '\r', '\n']) {
+            let escaped = name.replace(quote, &format!("{quote}{quote}"));
+            write!(w, "{quote}{escaped}{quote}")?;
+        } else {
+            w.write_all(name.as_bytes())?;
+        }
+        match names.get(&name) {
+            Some(&first) => duplicates.push(Duplicate {
+                first,
+                second: i,
+                name,
+            }),
+            None => {
+                names.insert(name, i);
+            }
+        }
+    }
+    w.write_all(terminator.as_bytes())?;
+
+    io::copy(r, w)?;
+    Ok(duplicates)
+}
+
+/// Parses the record with its line terminator. Returns `None` if the
+/// record ends inside a quoted field, meaning that it continues in the
+/// next line.
+fn parse(record: &str, delimiter: char, quote: char) -> Option<(Vec<Field>, &str)> {
+    let mut fields = Vec::new();
+    if record.is_empty() {
+        return Some((fields, ""));
+    }
+    let mut field = Field {
+        value: String::new(),
+        quoted: false,
+    };
+    let mut in_quotes = false;
+    let mut chars = record.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if in_quotes {
+            if c == quote {
+                if chars.peek().map(|&(_, c)| c) == Some(quote) {
+                    field.value.push(quote);
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.value.push(c);
+            }
+        } else if c == quote && !field.quoted && field.value.is_empty() {
+            field.quoted = true;
+            in_quotes = true;
+        } else if c == delimiter {
+            fields.push(field);
+            field = Field {
+                value: String::new(),
+                quoted: false,
+            };
+        } else if c == '\n' || (c == '\r' && record[i..].starts_with("\r\n")) {
+            fields.push(field);
+            return Some((fields, &record[i..]));
+        } else {
+            field.value.push(c);
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    fields.push(field);
+    Some((fields, ""))
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Detection of dialects of phrases.
+
+use crate::{dialect::Dialect, each_phrase, fail, stream::NonUtf8};
+use clap::ArgMatches;
+use std::io::Write;
+
+/// Runs the `detect` subcommand.
+pub fn run(matches: &ArgMatches) {
+    let expect = matches
+        .get_one::<Dialect>("expect")
+        .map(|dialect| dialect.0);
+    let mut mismatches = 0;
+    each_phrase(matches, NonUtf8::Error, |w, number, p| {
+        if p.is_empty() {
+            return Ok(());
+        }
+        let detected = casbab::detect(p);
+        let style = match detected.as_slice() {
+            [] => "mixed".to_string(),
+            [case] => case.name().to_string(),
+            cases => format!(
+                "ambiguous: {}",
+                cases
+                    .iter()
+                    .map(|c| c.name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        write!(w, "{p}\t{style}")?;
+        w.end()?;
+        if let Some(expect) = expect {
+            if !detected.contains(&expect) {
+                mismatches += 1;
+                eprintln!("{} {number}: `{p}` is not {}", w.record(), expect.name());
+            }
+        }
+        Ok(())
+    });
+    if let (Some(expect), 1..) = (expect, mismatches) {
+        fail(format!(
+            "phrases not written in {}: {mismatches}",
+            expect.name()
+        ));
+    }
+}
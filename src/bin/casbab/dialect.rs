@@ -0,0 +1,58 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Command line values of dialects.
+
+use casbab::Case;
+use clap::{builder::PossibleValue, ValueEnum};
+
+/// Dialect provided as a command line value, by its name or by one of the
+/// common aliases, ignoring letter case.
+#[derive(Clone, Copy)]
+pub struct Dialect(pub Case);
+
+const VARIANTS: [Dialect; Case::ALL.len()] = {
+    let mut variants = [Dialect(Case::Camel); Case::ALL.len()];
+    let mut i = 0;
+    while i < variants.len() {
+        variants[i] = Dialect(Case::ALL[i]);
+        i += 1;
+    }
+    variants
+};
+
+impl ValueEnum for Dialect {
+    fn value_variants<'a>() -> &'a [Self] {
+        &VARIANTS
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(PossibleValue::new(self.0.name()).aliases(aliases(self.0)))
+    }
+}
+
+/// Returns names of the dialect written in itself and names used by other
+/// tools.
+fn aliases(case: Case) -> &'static [&'static str] {
+    match case {
+        Case::Camel => &["camelCase", "camel-case"],
+        Case::Pascal => &["PascalCase", "pascal-case"],
+        Case::Snake => &["snake_case", "snake-case"],
+        Case::CamelSnake => &["Camel_Snake", "Camel_Snake_Case", "camel-snake-case"],
+        Case::ScreamingSnake => &[
+            "SCREAMING_SNAKE",
+            "SCREAMING_SNAKE_CASE",
+            "screaming-snake-case",
+            "constant",
+            "constant-case",
+        ],
+        Case::Kebab => &["kebab-case"],
+        Case::CamelKebab => &["Camel-Kebab", "Camel-Kebab-Case", "train", "train-case"],
+        Case::ScreamingKebab => &["SCREAMING-KEBAB", "SCREAMING-KEBAB-CASE"],
+        Case::Lower => &["lower-case"],
+        Case::Title => &["title-case"],
+        Case::Screaming => &["upper", "upper-case"],
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion of configuration paths into environment variable names.
+
+use crate::{
+    dialect::Dialect,
+    each_phrase,
+    stream::{self, NonUtf8},
+};
+use clap::ArgMatches;
+use std::io::Write;
+
+/// Runs the `env` subcommand.
+pub fn run(matches: &ArgMatches) {
+    let delimiters: Vec<char> = matches
+        .get_one::<String>("delimiters")
+        .expect("has default")
+        .chars()
+        .collect();
+    let env = casbab::env::Env::new()
+        .prefix(
+            matches
+                .get_one::<String>("prefix")
+                .map_or("", String::as_str),
+        )
+        .separator(matches.get_one::<String>("separator").expect("has default"))
+        .delimiters(&delimiters);
+    let inverse = matches
+        .get_one::<Dialect>("inverse")
+        .map(|dialect| dialect.0);
+    each_phrase(matches, NonUtf8::Error, |w, _, p| {
+        match inverse {
+            Some(case) => match env.path(p, case) {
+                Some(path) => write!(w, "{path}")?,
+                None => {
+                    return Err(stream::Error::Phrase(format!(
+                        "`{p}` does not start with the prefix"
+                    )))
+                }
+            },
+            None => write!(w, "{}", env.name(p))?,
+        }
+        w.end()?;
+        Ok(())
+    });
+}
@@ -0,0 +1,451 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use atty::Stream;
+use casbab::Case;
+use clap::{
+    arg, builder::NonEmptyStringValueParser, error::ErrorKind, value_parser, Arg, ArgMatches,
+    Command,
+};
+use clap_complete::Shell;
+use dialect::Dialect;
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::PathBuf,
+    process,
+};
+use stream::NonUtf8;
+
+mod all;
+mod completions;
+mod csv;
+mod detect;
+mod dialect;
+mod env;
+mod man;
+mod rename;
+mod rewrite;
+mod stream;
+
+/// Exit status when the output is closed before everything is written, the
+/// same as of processes terminated by the `SIGPIPE` signal.
+const BROKEN_PIPE_STATUS: i32 = 141;
+
+fn command() -> Command {
+    Command::new("casbab")
+        .about("Camel Snake Kebab (https://github.com/janos/casbab-rs)")
+        .version(env!("CARGO_PKG_VERSION"))
+        .disable_version_flag(true)
+        .arg_required_else_help(true)
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
+        .author("Janos Guljas <janos@resenje.org>")
+        .arg(arg!(<dialect>).value_parser(value_parser!(Dialect)).ignore_case(true).hide_possible_values(true).help(format!(
+            "Convert into dialect. Possible values:
+{}
+Names are not case sensitive, and common aliases, such as `kebab-case`,
+`SCREAMING_SNAKE`, `constant` or `upper`, are accepted too.
+
+If no phrases are provided as arguments, arguments will be read from the
+Stdin as the new-line separated list.
+",
+            Case::ALL
+                .iter()
+                .map(|case| format!(
+                    "- {:16} `{}`\n",
+                    case.name(),
+                    case.convert("camel snake kebab")
+                ))
+                .collect::<String>()
+        )))
+        .arg(
+            arg!(<phrases> ... "Phrases to convert, or files to read with --keys, --match and --only-style.")
+                .trailing_var_arg(true)
+                .required(false),
+        )
+        .arg(
+            arg!(--keys <format> "Convert keys of structured documents instead of phrases.
+Documents are read from files provided as arguments or from Stdin.")
+            .value_parser(["json", "toml", "yaml"])
+            .required(false),
+        )
+        .arg(
+            arg!(--match <pattern> "Convert only matches of the regular expression in text, leaving the rest
+of it unchanged. If the pattern has capture groups, only the first group is
+converted. Text is read from files provided as arguments or from Stdin, line
+by line, so matches do not span lines.")
+            .conflicts_with("keys")
+            .required(false),
+        )
+        .arg(
+            arg!(--"only-style" <dialect> "Convert only identifiers written in dialect, and in no other one, in text,
+leaving the rest of it unchanged, including single words that are written in
+many dialects. Text is read from files provided as arguments or from Stdin.")
+            .value_parser(value_parser!(Dialect))
+            .ignore_case(true)
+            .hide_possible_values(true)
+            .conflicts_with("keys")
+            .required(false),
+        )
+        .arg(
+            arg!(--exclude <pointer> "JSON Pointer of the value which keys are not converted.")
+                .action(clap::ArgAction::Append)
+                .requires("keys")
+                .required(false),
+        )
+        .arg(
+            arg!(--depth <levels> "Maximal number of nested levels which keys are converted.")
+                .value_parser(value_parser!(usize))
+                .requires("keys")
+                .required(false),
+        )
+        .arg(
+            arg!(--"non-utf8" <action> "Handling of lines of phrases or text that are not valid UTF-8. They either
+stop the conversion with an error, or are written unchanged. Documents with
+keys to convert must always be valid UTF-8.")
+            .value_parser(["error", "pass"])
+            .default_value("error"),
+        )
+        .args(delimiter_args())
+        .subcommand(
+            Command::new("completions")
+                .about("Generate shell completions")
+                .arg(
+                    arg!(<shell> "Shell to generate completions for.")
+                        .value_parser(value_parser!(Shell)),
+                ),
+        )
+        .subcommand(Command::new("man").about("Generate the man page in the roff format"))
+        .subcommand(
+            Command::new("all")
+                .about("Convert a phrase into all dialects")
+                .arg(
+                    arg!(--format <format> "Output format.")
+                        .value_parser(["table", "json", "tsv"])
+                        .default_value("table"),
+                )
+                .arg(
+                    arg!(<phrase> ... "Phrase to convert. Multiple arguments are joined with spaces.")
+                        .trailing_var_arg(true),
+                ),
+        )
+        .subcommand(
+            Command::new("csv-headers")
+                .about("Convert column names in the header row of a CSV document")
+                .arg(
+                    arg!(<dialect> "Convert into dialect. See `casbab --help` for possible values.")
+                        .value_parser(value_parser!(Dialect))
+                        .ignore_case(true)
+                        .hide_possible_values(true),
+                )
+                .arg(arg!([file] "CSV file to read instead of Stdin."))
+                .arg(
+                    arg!(--delimiter <char> "Field delimiter.")
+                        .value_parser(value_parser!(char))
+                        .default_value(","),
+                )
+                .arg(
+                    arg!(--quote <char> "Quote character.")
+                        .value_parser(value_parser!(char))
+                        .default_value("\""),
+                ),
+        )
+        .subcommand(
+            Command::new("detect")
+                .about("Detect the dialect of phrases")
+                .long_about(
+                    "Detect the dialect of phrases. Phrases that are written in more than one dialect,
+such as single words, are reported as ambiguous, and phrases that are not written
+in any dialect are reported as mixed. Empty phrases are skipped.",
+                )
+                .arg(
+                    arg!(--expect <dialect> "Exit with an error if any phrase is not written in dialect.")
+                        .value_parser(value_parser!(Dialect))
+                        .ignore_case(true)
+                        .hide_possible_values(true),
+                )
+                .args(delimiter_args())
+                .arg(
+                    arg!(<phrases> ... "Phrases to detect. If not provided, they are read from Stdin.")
+                        .trailing_var_arg(true)
+                        .required(false),
+                ),
+        )
+        .subcommand(
+            Command::new("rename")
+                .about("Rename files and directories")
+                .long_about(
+                    "Rename files and directories. Stems of file names are converted and extensions are
+preserved. Nothing is renamed if any of the new names collides with another one or
+with an existing file.",
+                )
+                .arg(
+                    arg!(<dialect> "Convert into dialect. See `casbab --help` for possible values.")
+                        .value_parser(value_parser!(Dialect))
+                        .ignore_case(true)
+                        .hide_possible_values(true),
+                )
+                .arg(arg!(-r --recursive "Also rename files and directories in directories."))
+                .arg(arg!(--"dry-run" "Print renames without renaming files."))
+                .arg(
+                    arg!(<paths> ... "Files and directories to rename.")
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("rewrite")
+                .about("Convert identifiers in text files in place")
+                .arg(
+                    arg!(--from <dialect> "Convert only identifiers that are written in dialect and in no other one.")
+                        .value_parser(value_parser!(Dialect))
+                        .ignore_case(true)
+                        .hide_possible_values(true),
+                )
+                .arg(
+                    arg!(--to <dialect> "Convert identifiers into dialect.")
+                        .value_parser(value_parser!(Dialect))
+                        .ignore_case(true)
+                        .hide_possible_values(true)
+                        .required(true),
+                )
+                .arg(arg!(--pattern <regex> "Regular expression that matches identifiers. By default,
+identifiers are words of letters, digits and underscores, and
+also hyphens if converting from a kebab dialect. If the pattern
+has capture groups, only the first group is converted."))
+                .arg(arg!(--"dry-run" "Print the diff of changes instead of rewriting files."))
+                .arg(arg!(<files> ... "Files to rewrite.")),
+        )
+        .subcommand(
+            Command::new("env")
+                .about("Convert configuration paths into environment variable names")
+                .arg(arg!(--prefix <prefix> "Prefix of environment variable names."))
+                .arg(
+                    arg!(--separator <separator> "Separator between nested path segments in names.")
+                        .default_value("__"),
+                )
+                .arg(
+                    arg!(--delimiters <chars> "Characters that separate segments in paths.")
+                        .default_value("."),
+                )
+                .arg(
+                    arg!(--inverse <dialect> "Convert names back into paths with segments in dialect.")
+                        .value_parser(value_parser!(Dialect))
+                        .ignore_case(true)
+                        .hide_possible_values(true),
+                )
+                .args(delimiter_args())
+                .arg(
+                    arg!(<phrases> ... "Paths or names to convert.")
+                        .trailing_var_arg(true)
+                        .required(false),
+                ),
+        )
+}
+
+fn main() {
+    let matches = command().get_matches();
+    match matches.subcommand() {
+        Some(("all", matches)) => all::run(matches),
+        Some(("completions", matches)) => completions::run(matches),
+        Some(("csv-headers", matches)) => csv::run(matches),
+        Some(("detect", matches)) => detect::run(matches),
+        Some(("env", matches)) => env::run(matches),
+        Some(("man", matches)) => man::run(matches),
+        Some(("rename", matches)) => rename::run(matches),
+        Some(("rewrite", matches)) => rewrite::run(matches),
+        _ => convert(&matches),
+    }
+}
+
+/// Converts phrases, keys of documents or identifiers in text into the
+/// dialect, depending on the options.
+fn convert(matches: &ArgMatches) {
+    let Dialect(case) = *matches
+        .get_one::<Dialect>("dialect")
+        .expect("`dialect` is required");
+    let non_utf8 = match matches.get_one::<String>("non-utf8").map(String::as_str) {
+        Some("pass") => NonUtf8::Pass,
+        _ => NonUtf8::Error,
+    };
+
+    if let Some(format) = matches.get_one::<String>("keys") {
+        let exclusions: Vec<&str> = matches
+            .get_many::<String>("exclude")
+            .map(|e| e.map(String::as_str).collect())
+            .unwrap_or_default();
+        let depth_limit = matches.get_one::<usize>("depth").copied();
+        let mut w = stdout();
+        each_source(matches, |name, r| {
+            let mut document = Vec::new();
+            if let Err(e) = r.read_to_end(&mut document) {
+                fail(format!("read {name}: {e}"));
+            }
+            let document = String::from_utf8(document)
+                .unwrap_or_else(|_| fail(format!("{name}: invalid UTF-8")));
+            let result = match format.as_str() {
+                "json" => casbab::json::convert_str(&document, case, depth_limit, &exclusions)
+                    .map(|s| s + "\n")
+                    .map_err(|e| e.to_string()),
+                "toml" => casbab::toml::convert_str(&document, case, depth_limit, &exclusions)
+                    .map_err(|e| e.to_string()),
+                _ => casbab::yaml::convert_str(&document, case, depth_limit, &exclusions)
+                    .map_err(|e| e.to_string()),
+            };
+            match result {
+                Ok(document) => {
+                    if let Err(e) = w.write_all(document.as_bytes()) {
+                        write_failed(e);
+                    }
+                }
+                Err(e) => fail(e),
+            }
+        });
+        if let Err(e) = w.flush() {
+            write_failed(e);
+        }
+        return;
+    }
+
+    if matches.contains_id("match") || matches.contains_id("only-style") {
+        let from = matches
+            .get_one::<Dialect>("only-style")
+            .map(|dialect| dialect.0);
+        let pattern = matches
+            .get_one::<String>("match")
+            .map_or(rewrite::default_pattern(from), String::as_str);
+        let pattern =
+            regex::Regex::new(pattern).unwrap_or_else(|e| fail(format!("invalid pattern: {e}")));
+        let mut w = stdout();
+        each_source(matches, |name, r| {
+            let mut line = Vec::new();
+            let mut number = 0;
+            loop {
+                line.clear();
+                match r.read_until(b'\n', &mut line) {
+                    Ok(0) => return,
+                    Ok(_) => number += 1,
+                    Err(e) => fail(format!("read {name}: {e}")),
+                }
+                let result = match (std::str::from_utf8(&line), non_utf8) {
+                    (Ok(text), _) => {
+                        let (rewritten, _) = rewrite::rewrite(text, &pattern, from, case);
+                        w.write_all(rewritten.as_bytes())
+                    }
+                    (Err(_), NonUtf8::Pass) => w.write_all(&line),
+                    (Err(_), NonUtf8::Error) => {
+                        if let Err(e) = w.flush() {
+                            write_failed(e);
+                        }
+                        fail(format!("{name}: line {number}: invalid UTF-8"));
+                    }
+                };
+                if let Err(e) = result {
+                    write_failed(e);
+                }
+            }
+        });
+        if let Err(e) = w.flush() {
+            write_failed(e);
+        }
+        return;
+    }
+
+    each_phrase(matches, non_utf8, |w, _, p| {
+        write!(w, "{}", case.display(p))?;
+        w.end()?;
+        Ok(())
+    });
+}
+
+/// Calls the function for every phrase provided as an argument or read from
+/// Stdin, writing records to the buffered Stdout. Exits if any phrase fails.
+fn each_phrase(
+    matches: &ArgMatches,
+    non_utf8: NonUtf8,
+    mut f: impl FnMut(
+        &mut stream::Output<BufWriter<io::StdoutLock<'static>>>,
+        usize,
+        &str,
+    ) -> Result<(), stream::Error>,
+) {
+    let delimiter = match matches.get_one::<String>("delimiter") {
+        Some(delimiter) => delimiter.as_bytes().to_vec(),
+        None if matches.get_flag("null") => vec![0],
+        None => vec![b'\n'],
+    };
+    let mut w = stream::Output::new(stdout(), delimiter);
+    let result = match matches.get_many::<String>("phrases") {
+        Some(phrases) => phrases
+            .enumerate()
+            .try_for_each(|(i, p)| f(&mut w, i + 1, p)),
+        None => {
+            if atty::is(Stream::Stdin) {
+                command()
+                    .error(
+                        ErrorKind::InvalidValue,
+                        "Missing phases either from arguments or stdin",
+                    )
+                    .exit();
+            }
+            stream::each_record(io::stdin().lock(), &mut w, non_utf8, f)
+        }
+    };
+    let flushed = w.flush().map_err(stream::Error::from);
+    if let Err(e) = result.and(flushed) {
+        if e.is_broken_pipe() {
+            process::exit(BROKEN_PIPE_STATUS);
+        }
+        fail(e.to_string());
+    }
+}
+
+/// Calls the function with the name and the reader of every file provided
+/// as an argument, or of Stdin if there are none.
+fn each_source(matches: &ArgMatches, mut f: impl FnMut(&str, &mut dyn BufRead)) {
+    match matches.get_many::<String>("phrases") {
+        None => f("stdin", &mut io::stdin().lock()),
+        Some(files) => {
+            for file in files {
+                let r = fs::File::open(file).unwrap_or_else(|e| fail(format!("read {file}: {e}")));
+                f(file, &mut BufReader::new(r));
+            }
+        }
+    }
+}
+
+/// Returns options for delimiters of phrases in Stdin and of records in
+/// Stdout.
+fn delimiter_args() -> [Arg; 2] {
+    [
+        arg!(-'0' --null "Delimit phrases and output records with NUL characters instead of new
+lines, for example to read paths from `find -print0` or to pass them to
+`xargs -0`."),
+        arg!(--delimiter <str> "Delimit phrases and output records with the string instead of new lines.")
+            .value_parser(NonEmptyStringValueParser::new())
+            .conflicts_with("null")
+            .required(false),
+    ]
+}
+
+/// Returns the locked and buffered Stdout, which must be flushed explicitly
+/// so that errors of the last write are not ignored.
+fn stdout() -> BufWriter<io::StdoutLock<'static>> {
+    BufWriter::new(io::stdout().lock())
+}
+
+/// Exits after a failed write to Stdout, with `BROKEN_PIPE_STATUS` and no
+/// message if the output was closed early.
+fn write_failed(e: io::Error) -> ! {
+    if e.kind() == io::ErrorKind::BrokenPipe {
+        process::exit(BROKEN_PIPE_STATUS);
+    }
+    fail(e.to_string());
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("error: {message}");
+    process::exit(1);
+}
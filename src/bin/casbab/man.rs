@@ -0,0 +1,21 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Generation of the man page.
+
+use crate::{command, stdout, write_failed};
+use clap::ArgMatches;
+use std::io::Write;
+
+/// Runs the `man` subcommand.
+pub fn run(_: &ArgMatches) {
+    let mut w = stdout();
+    if let Err(e) = clap_mangen::Man::new(command())
+        .render(&mut w)
+        .and_then(|_| w.flush())
+    {
+        write_failed(e);
+    }
+}
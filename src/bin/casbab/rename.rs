@@ -0,0 +1,227 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Renaming of files and directories.
+
+use crate::{dialect::Dialect, fail, stdout, write_failed};
+use casbab::Case;
+use clap::ArgMatches;
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// Runs the `rename` subcommand.
+pub fn run(matches: &ArgMatches) {
+    let Dialect(case) = *matches
+        .get_one::<Dialect>("dialect")
+        .expect("`dialect` is required");
+    let paths: Vec<PathBuf> = matches
+        .get_many::<PathBuf>("paths")
+        .expect("`paths` is required")
+        .cloned()
+        .collect();
+    let (renames, errors) =
+        plan(case, &paths, matches.get_flag("recursive")).unwrap_or_else(|e| fail(e.to_string()));
+    for error in errors {
+        eprintln!("warning: {error}");
+    }
+    let collisions = collisions(&renames);
+    if !collisions.is_empty() {
+        for collision in &collisions {
+            eprintln!("error: {collision}");
+        }
+        fail(format!(
+            "{} collisions, nothing is renamed",
+            collisions.len()
+        ));
+    }
+    let dry_run = matches.get_flag("dry-run");
+    let mut w = stdout();
+    for r in &renames {
+        if let Err(e) = writeln!(w, "{} -> {}", r.from.display(), r.to.display()) {
+            write_failed(e);
+        }
+        if !dry_run {
+            if let Err(e) = rename(r) {
+                fail(format!("rename {}: {e}", r.from.display()));
+            }
+        }
+    }
+    if let Err(e) = w.flush() {
+        write_failed(e);
+    }
+}
+
+/// Planned renaming of a file or a directory.
+pub struct Rename {
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+/// Returns renames of the paths, and of all files and directories in them
+/// if recursive. Files in directories are renamed before directories, so
+/// that their paths are still valid when they are renamed. Names that are
+/// not valid UTF-8 are returned as errors and they are not renamed.
+pub fn plan(
+    case: Case,
+    paths: &[PathBuf],
+    recursive: bool,
+) -> io::Result<(Vec<Rename>, Vec<String>)> {
+    let mut renames = Vec::new();
+    let mut errors = Vec::new();
+    for path in paths {
+        let metadata = fs::symlink_metadata(path)?;
+        if recursive && metadata.is_dir() {
+            walk(case, path, &mut renames, &mut errors)?;
+        }
+        push(case, path, metadata.is_dir(), &mut renames, &mut errors);
+    }
+    Ok((renames, errors))
+}
+
+fn walk(
+    case: Case,
+    dir: &Path,
+    renames: &mut Vec<Rename>,
+    errors: &mut Vec<String>,
+) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let is_dir = entry.file_type()?.is_dir();
+        if is_dir {
+            walk(case, &path, renames, errors)?;
+        }
+        push(case, &path, is_dir, renames, errors);
+    }
+    Ok(())
+}
+
+fn push(
+    case: Case,
+    path: &Path,
+    is_dir: bool,
+    renames: &mut Vec<Rename>,
+    errors: &mut Vec<String>,
+) {
+    let Some(name) = path.file_name() else {
+        return;
+    };
+    let Some(name) = name.to_str() else {
+        errors.push(format!("{}: name is not valid UTF-8", path.display()));
+        return;
+    };
+    let converted = convert_name(case, name, is_dir);
+    if converted.is_empty() {
+        errors.push(format!(
+            "{}: name is empty after conversion",
+            path.display()
+        ));
+    } else if converted != name {
+        renames.push(Rename {
+            from: path.to_path_buf(),
+            to: path.with_file_name(converted),
+        });
+    }
+}
+
+/// Converts the file stem and preserves the extension. Names of
+/// directories and hidden files without an extension are converted
+/// whole.
+fn convert_name(case: Case, name: &str, is_dir: bool) -> String {
+    match name.rfind('.') {
+        Some(i) if i > 0 && !is_dir => {
+            let (stem, extension) = name.split_at(i);
+            case.convert(stem) + extension
+        }
+        _ => case.convert(name),
+    }
+}
+
+/// Returns descriptions of renames that would overwrite an existing file
+/// or that have the same target. Targets that differ only in letter case
+/// are the same on case insensitive file systems.
+pub fn collisions(renames: &[Rename]) -> Vec<String> {
+    let mut collisions = Vec::new();
+    let mut targets: HashMap<String, &Rename> = HashMap::new();
+    for rename in renames {
+        let target = rename.to.to_string_lossy().to_lowercase();
+        if let Some(other) = targets.insert(target, rename) {
+            if other.to == rename.to {
+                collisions.push(format!(
+                    "{} and {} are both renamed to {}",
+                    other.from.display(),
+                    rename.from.display(),
+                    rename.to.display()
+                ));
+            } else {
+                collisions.push(format!(
+                    "{} and {} are renamed to {} and {}, which differ only in letter case",
+                    other.from.display(),
+                    rename.from.display(),
+                    other.to.display(),
+                    rename.to.display()
+                ));
+            }
+        } else if fs::symlink_metadata(&rename.to).is_ok() && !same_file(&rename.from, &rename.to) {
+            collisions.push(format!(
+                "{} is renamed to existing {}",
+                rename.from.display(),
+                rename.to.display()
+            ));
+        }
+    }
+    collisions
+}
+
+/// Renames the file. Renames that change only the letter case are done
+/// in two steps through a temporary name, as case insensitive file
+/// systems may ignore them otherwise.
+pub fn rename(rename: &Rename) -> io::Result<()> {
+    let from = rename.from.to_string_lossy();
+    let to = rename.to.to_string_lossy();
+    if from.to_lowercase() != to.to_lowercase() {
+        return fs::rename(&rename.from, &rename.to);
+    }
+    let mut temporary = rename.from.as_os_str().to_owned();
+    temporary.push(".casbab-rename");
+    let temporary = PathBuf::from(temporary);
+    if fs::symlink_metadata(&temporary).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("temporary {} already exists", temporary.display()),
+        ));
+    }
+    fs::rename(&rename.from, &temporary)?;
+    fs::rename(&temporary, &rename.to)
+}
+
+/// Returns true if both paths refer to the same file, which happens for
+/// names that differ only in letter case on case insensitive file
+/// systems.
+#[cfg(unix)]
+fn same_file(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (fs::symlink_metadata(a), fs::symlink_metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+        _ => false,
+    }
+}
+
+/// Returns true if both paths refer to the same file, which happens for
+/// names that differ only in letter case on case insensitive file
+/// systems.
+#[cfg(not(unix))]
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
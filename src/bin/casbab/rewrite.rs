@@ -0,0 +1,134 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Rewriting of identifiers in text files.
+
+use crate::{dialect::Dialect, fail, stdout, write_failed};
+use casbab::Case;
+use clap::ArgMatches;
+use regex::Regex;
+use similar::TextDiff;
+use std::{fs, io::Write, process};
+
+/// Runs the `rewrite` subcommand.
+pub fn run(matches: &ArgMatches) {
+    let from = matches.get_one::<Dialect>("from").map(|dialect| dialect.0);
+    let Dialect(to) = *matches.get_one::<Dialect>("to").expect("`to` is required");
+    let pattern = matches
+        .get_one::<String>("pattern")
+        .map_or(default_pattern(from), String::as_str);
+    let pattern = Regex::new(pattern).unwrap_or_else(|e| fail(format!("invalid pattern: {e}")));
+    let dry_run = matches.get_flag("dry-run");
+
+    let mut w = stdout();
+    let (mut total, mut changed, mut errors) = (0, 0, 0);
+    for file in matches
+        .get_many::<String>("files")
+        .expect("`files` is required")
+    {
+        let text = match fs::read_to_string(file) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("error: read {file}: {e}");
+                errors += 1;
+                continue;
+            }
+        };
+        let (rewritten, count) = rewrite(&text, &pattern, from, to);
+        if count == 0 {
+            continue;
+        }
+        if dry_run {
+            if let Err(e) = w.write_all(diff(file, &text, &rewritten).as_bytes()) {
+                write_failed(e);
+            }
+        } else if let Err(e) = fs::write(file, rewritten) {
+            eprintln!("error: write {file}: {e}");
+            errors += 1;
+            continue;
+        }
+        eprintln!("{file}: {count} {}", plural(count, "identifier"));
+        total += count;
+        changed += 1;
+    }
+    if let Err(e) = w.flush() {
+        write_failed(e);
+    }
+    let verb = if dry_run { "to rewrite" } else { "rewritten" };
+    eprintln!(
+        "{total} {} in {changed} {} {verb}",
+        plural(total, "identifier"),
+        plural(changed, "file")
+    );
+    if errors > 0 {
+        process::exit(1);
+    }
+}
+
+/// Returns the noun in the plural form, unless the count is one.
+fn plural(count: usize, noun: &str) -> String {
+    match count {
+        1 => noun.to_string(),
+        _ => format!("{noun}s"),
+    }
+}
+
+/// Identifiers made of letters, digits and underscores.
+const IDENTIFIER: &str = r"\b[\p{L}_][\p{L}\p{N}_]*\b";
+
+/// Identifiers that may also contain hyphens between words, used when
+/// converting from dialects of the *Kebab* family.
+const KEBAB_IDENTIFIER: &str = r"\b[\p{L}_][\p{L}\p{N}_]*(?:-[\p{L}\p{N}_]+)*\b";
+
+/// Returns the pattern that finds identifiers if no pattern is provided.
+pub fn default_pattern(from: Option<Case>) -> &'static str {
+    match from {
+        Some(Case::Kebab | Case::CamelKebab | Case::ScreamingKebab) => KEBAB_IDENTIFIER,
+        _ => IDENTIFIER,
+    }
+}
+
+/// Converts all matches of the pattern that are written in the from
+/// dialect, or all of them if it is not set, and returns the new text
+/// with the number of replaced identifiers. Matches that are written in
+/// more than one dialect, such as single lower case words, are not
+/// converted if the from dialect is set, so that keywords and prose are
+/// left unchanged. If the pattern has capture groups, only the text
+/// matched by the first one is converted, so that the rest of the pattern
+/// can match the context of identifiers.
+pub fn rewrite(text: &str, pattern: &Regex, from: Option<Case>, to: Case) -> (String, usize) {
+    let mut count = 0;
+    let rewritten = pattern.replace_all(text, |captures: &regex::Captures| {
+        let all = captures.get(0).expect("match always has the group 0");
+        let m = match captures.get(1) {
+            Some(m) => m,
+            None if captures.len() > 1 => return all.as_str().to_string(),
+            None => all,
+        };
+        let identifier = m.as_str();
+        let mut replacement = String::from(&all.as_str()[..m.start() - all.start()]);
+        match to.convert(identifier) {
+            converted
+                if converted != identifier
+                    && from.is_none_or(|from| casbab::detect(identifier) == [from]) =>
+            {
+                count += 1;
+                replacement.push_str(&converted);
+            }
+            _ => replacement.push_str(identifier),
+        }
+        replacement.push_str(&all.as_str()[m.end() - all.start()..]);
+        replacement
+    });
+    (rewritten.into_owned(), count)
+}
+
+/// Returns the unified diff between the old and the new file content.
+pub fn diff(path: &str, old: &str, new: &str) -> String {
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .header(path, path)
+        .to_string()
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Record by record processing of phrases read from Stdin.
+
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+/// Handling of records that are not valid UTF-8.
+#[derive(Clone, Copy)]
+pub enum NonUtf8 {
+    /// Stop with an error that has the record number.
+    Error,
+    /// Write the record unchanged.
+    Pass,
+}
+
+pub enum Error {
+    Io(io::Error),
+    Utf8 { record: &'static str, number: usize },
+    Phrase(String),
+}
+
+impl Error {
+    /// Returns true if the output was closed before everything was written
+    /// to it, for example by `head`.
+    pub fn is_broken_pipe(&self) -> bool {
+        matches!(self, Error::Io(e) if e.kind() == io::ErrorKind::BrokenPipe)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Utf8 { record, number } => write!(f, "{record} {number}: invalid UTF-8"),
+            Error::Phrase(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Writer of records that are ended with the delimiter.
+pub struct Output<W: Write> {
+    w: W,
+    delimiter: Vec<u8>,
+}
+
+impl<W: Write> Output<W> {
+    pub fn new(w: W, delimiter: Vec<u8>) -> Self {
+        Output { w, delimiter }
+    }
+
+    /// Returns the name of records in messages, `line` if they are
+    /// delimited by new lines and `record` otherwise.
+    pub fn record(&self) -> &'static str {
+        if self.delimiter == b"\n" {
+            "line"
+        } else {
+            "record"
+        }
+    }
+
+    /// Ends the current record.
+    pub fn end(&mut self) -> io::Result<()> {
+        self.w.write_all(&self.delimiter)
+    }
+}
+
+impl<W: Write> Write for Output<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.w.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.w.flush()
+    }
+}
+
+/// Calls the function for every record, separated by the same delimiter
+/// as the output records, with its one based number, without reading the
+/// whole input into memory. Delimiters are not passed to the function,
+/// which ends its own record. If records are delimited by new lines, `\r`
+/// before them is removed too.
+pub fn each_record<R: BufRead, W: Write>(
+    mut r: R,
+    w: &mut Output<W>,
+    non_utf8: NonUtf8,
+    mut f: impl FnMut(&mut Output<W>, usize, &str) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let delimiter = w.delimiter.clone();
+    let last = *delimiter.last().expect("delimiter is not empty");
+    let mut buf = Vec::new();
+    let mut number = 0;
+    loop {
+        buf.clear();
+        while r.read_until(last, &mut buf)? > 0 && !buf.ends_with(&delimiter) {}
+        if buf.is_empty() {
+            return Ok(());
+        }
+        number += 1;
+        let record = match buf.strip_suffix(delimiter.as_slice()) {
+            Some(record) if delimiter == b"\n" => record.strip_suffix(b"\r").unwrap_or(record),
+            Some(record) => record,
+            None => &buf,
+        };
+        match (std::str::from_utf8(record), non_utf8) {
+            (Ok(record), _) => f(w, number, record)?,
+            (Err(_), NonUtf8::Pass) => {
+                w.write_all(record)?;
+                w.end()?;
+            }
+            (Err(_), NonUtf8::Error) => {
+                return Err(Error::Utf8 {
+                    record: w.record(),
+                    number,
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion between nested configuration paths and environment
+//! variable names.
+//!
+//! Every segment of the path is converted into *Screaming snake* case
+//! and segments are joined with the nesting separator, which is
+//! distinct from the underscore between words:
+//!
+//! ```
+//! use casbab::{env::Env, Case};
+//!
+//! let env = Env::new().prefix("app");
+//! assert_eq!(env.name("database.pool.maxSize"), "APP_DATABASE__POOL__MAX_SIZE");
+//! assert_eq!(
+//!     env.path("APP_DATABASE__POOL__MAX_SIZE", Case::Camel).as_deref(),
+//!     Some("database.pool.maxSize")
+//! );
+//! ```
+//!
+//! Names contain only characters `A-Z`, `0-9` and `_`. Any other
+//! character that remains after the conversion is treated as a word
+//! separator, and a name that would start with a digit is prefixed
+//! with an underscore.
+
+use crate::Case;
+use alloc::string::String;
+
+/// Options for converting paths into environment variable names and
+/// back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Env<'a> {
+    prefix: &'a str,
+    separator: &'a str,
+    delimiters: &'a [char],
+}
+
+impl<'a> Env<'a> {
+    /// Returns options without a prefix, with `__` as the nesting
+    /// separator and `.` as the path delimiter.
+    pub const fn new() -> Self {
+        Self {
+            prefix: "",
+            separator: "__",
+            delimiters: &['.'],
+        }
+    }
+
+    /// Sets the prefix that is converted and joined to the name with an
+    /// underscore.
+    pub const fn prefix(self, prefix: &'a str) -> Self {
+        Self { prefix, ..self }
+    }
+
+    /// Sets the separator between nested path segments in names. It
+    /// should not be a single underscore, as it would not be possible
+    /// to distinguish segments from words.
+    pub const fn separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Sets characters that separate segments in paths. The first one is
+    /// used when paths are constructed from names.
+    pub const fn delimiters(self, delimiters: &'a [char]) -> Self {
+        Self { delimiters, ..self }
+    }
+
+    /// Converts the path into the environment variable name.
+    ///
+    /// Example: `database.pool.maxSize` with the `app` prefix returns
+    /// `APP_DATABASE__POOL__MAX_SIZE`.
+    pub fn name(&self, path: &str) -> String {
+        let mut name = String::new();
+        sanitize(&mut name, self.prefix);
+        let mut first = true;
+        for segment in path.split(self.delimiters) {
+            let mut s = String::new();
+            sanitize(&mut s, segment);
+            if s.is_empty() {
+                continue;
+            }
+            if first {
+                if !name.is_empty() {
+                    name.push('_');
+                }
+            } else {
+                name.push_str(self.separator);
+            }
+            name.push_str(&s);
+            first = false;
+        }
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.insert(0, '_');
+        }
+        name
+    }
+
+    /// Converts the environment variable name into the path with segments
+    /// converted into the case. Returns `None` if the name does not
+    /// start with the prefix.
+    ///
+    /// Example: `APP_DATABASE__POOL__MAX_SIZE` with the `app` prefix and
+    /// the *Camel* case returns `database.pool.maxSize`.
+    pub fn path(&self, name: &str, case: Case) -> Option<String> {
+        let prefix = self.name("");
+        let rest = if prefix.is_empty() {
+            match name.strip_prefix('_') {
+                Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+                _ => name,
+            }
+        } else {
+            let rest = name.strip_prefix(prefix.as_str())?;
+            match rest.strip_prefix('_') {
+                Some(rest) => rest,
+                None if rest.is_empty() => rest,
+                None => return None,
+            }
+        };
+        let delimiter = self.delimiters.first().copied().unwrap_or('.');
+        let mut path = String::new();
+        for segment in rest.split(self.separator).filter(|s| !s.is_empty()) {
+            if !path.is_empty() {
+                path.push(delimiter);
+            }
+            path.push_str(&case.convert(segment));
+        }
+        Some(path)
+    }
+}
+
+impl Default for Env<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Appends *Screaming snake* case of the string with words that contain
+/// only ASCII uppercase letters and digits.
+fn sanitize(r: &mut String, s: &str) {
+    let start = r.len();
+    let mut separate = false;
+    for c in Case::ScreamingSnake.convert(s).chars() {
+        if c.is_ascii_uppercase() || c.is_ascii_digit() {
+            if separate && r.len() > start {
+                r.push('_');
+            }
+            r.push(c);
+            separate = false;
+        } else {
+            separate = true;
+        }
+    }
+}
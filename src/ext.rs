@@ -0,0 +1,96 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use alloc::string::String;
+
+use crate::Case;
+
+/// Extension trait that adds conversion methods to strings and any
+/// other type that can be referenced as a string, such as `String`
+/// or `Cow<str>`.
+///
+/// The trait has to be imported to be used:
+///
+/// ```
+/// use casbab::CasbabExt;
+///
+/// assert_eq!("HttpServer".to_snake(), "http_server");
+/// assert!("http_server".is_case(casbab::Case::Snake));
+/// ```
+pub trait CasbabExt {
+    /// Converts into the case, the same as [`Case::convert`].
+    fn to_case(&self, case: Case) -> String;
+
+    /// Returns true if the phrase is written in the case, the same as
+    /// [`Case::matches`].
+    fn is_case(&self, case: Case) -> bool;
+
+    /// Converts into *Camel* case, the same as [`crate::camel`].
+    fn to_camel(&self) -> String {
+        self.to_case(Case::Camel)
+    }
+
+    /// Converts into *Pascal* case, the same as [`crate::pascal`].
+    fn to_pascal(&self) -> String {
+        self.to_case(Case::Pascal)
+    }
+
+    /// Converts into *Snake* case, the same as [`crate::snake`].
+    fn to_snake(&self) -> String {
+        self.to_case(Case::Snake)
+    }
+
+    /// Converts into *Camel snake* case, the same as [`crate::camel_snake`].
+    fn to_camel_snake(&self) -> String {
+        self.to_case(Case::CamelSnake)
+    }
+
+    /// Converts into *Screaming snake* case, the same as
+    /// [`crate::screaming_snake`].
+    fn to_screaming_snake(&self) -> String {
+        self.to_case(Case::ScreamingSnake)
+    }
+
+    /// Converts into *Kebab* case, the same as [`crate::kebab`].
+    fn to_kebab(&self) -> String {
+        self.to_case(Case::Kebab)
+    }
+
+    /// Converts into *Camel kebab* case, the same as [`crate::camel_kebab`].
+    fn to_camel_kebab(&self) -> String {
+        self.to_case(Case::CamelKebab)
+    }
+
+    /// Converts into *Screaming kebab* case, the same as
+    /// [`crate::screaming_kebab`].
+    fn to_screaming_kebab(&self) -> String {
+        self.to_case(Case::ScreamingKebab)
+    }
+
+    /// Converts into *Lower* case, the same as [`crate::lower`].
+    fn to_lower(&self) -> String {
+        self.to_case(Case::Lower)
+    }
+
+    /// Converts into *Title* case, the same as [`crate::title`].
+    fn to_title(&self) -> String {
+        self.to_case(Case::Title)
+    }
+
+    /// Converts into *Screaming* case, the same as [`crate::screaming`].
+    fn to_screaming(&self) -> String {
+        self.to_case(Case::Screaming)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> CasbabExt for T {
+    fn to_case(&self, case: Case) -> String {
+        case.convert(self.as_ref())
+    }
+
+    fn is_case(&self, case: Case) -> bool {
+        case.matches(self.as_ref())
+    }
+}
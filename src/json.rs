@@ -0,0 +1,135 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion of object keys in JSON values.
+
+use std::{error, fmt};
+
+use serde_json::Value;
+
+pub use crate::keys::KeyCollision;
+use crate::{
+    keys::{check_collisions, Exclusions},
+    Case,
+};
+
+/// Recursively converts keys of all objects in the value into the case.
+///
+/// Depth limit is the number of nested object levels which keys are
+/// converted, where `Some(1)` converts only keys of the top level
+/// object and `None` converts keys at any depth. Arrays do not count
+/// as a level.
+///
+/// Exclusions are JSON Pointers of values which keys, and keys of all
+/// values nested in them, are not converted, such as free-form
+/// `/metadata` maps. Pointers are matched against the original keys and
+/// the `*` segment matches any key or array index, for example
+/// `/items/*/labels`. The key of the excluded value itself is converted.
+///
+/// If any two keys of the same object would be converted into the same
+/// key, the error is returned and the value is not changed.
+///
+/// Example: converting `{"user_id": 1, "metadata": {"a_b": 2}}` into
+/// `Case::Camel` with `/metadata` exclusion results in
+/// `{"userId": 1, "metadata": {"a_b": 2}}`.
+pub fn convert_keys(
+    value: &mut Value,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &[&str],
+) -> Result<(), KeyCollision> {
+    let exclusions = Exclusions::new(exclusions);
+    let mut path = Vec::new();
+    walk(value, case, depth_limit, &exclusions, &mut path, false)?;
+    walk(value, case, depth_limit, &exclusions, &mut path, true)
+}
+
+/// Parses the JSON document, converts its keys with [`convert_keys`] and
+/// returns the pretty formatted document.
+pub fn convert_str(
+    s: &str,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &[&str],
+) -> Result<String, Error> {
+    let mut value: Value = serde_json::from_str(s).map_err(Error::Parse)?;
+    convert_keys(&mut value, case, depth_limit, exclusions).map_err(Error::KeyCollision)?;
+    serde_json::to_string_pretty(&value).map_err(Error::Format)
+}
+
+/// Error returned by [`convert_str`].
+#[derive(Debug)]
+pub enum Error {
+    /// The document is not valid JSON.
+    Parse(serde_json::Error),
+    /// The converted document can not be formatted as JSON.
+    Format(serde_json::Error),
+    /// Two keys are converted into the same key.
+    KeyCollision(KeyCollision),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "parse json: {e}"),
+            Error::Format(e) => write!(f, "format json: {e}"),
+            Error::KeyCollision(e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(e) | Error::Format(e) => Some(e),
+            Error::KeyCollision(e) => Some(e),
+        }
+    }
+}
+
+/// Walks the value checking for collisions, or converting keys if apply
+/// is true.
+fn walk(
+    value: &mut Value,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &Exclusions,
+    path: &mut Vec<String>,
+    apply: bool,
+) -> Result<(), KeyCollision> {
+    match value {
+        Value::Object(map) => {
+            if depth_limit == Some(0) || exclusions.matches(path) {
+                return Ok(());
+            }
+            let depth_limit = depth_limit.map(|d| d - 1);
+            if !apply {
+                check_collisions(case, map.keys().map(String::as_str), path)?;
+                for (key, value) in map.iter_mut() {
+                    path.push(key.clone());
+                    walk(value, case, depth_limit, exclusions, path, apply)?;
+                    path.pop();
+                }
+                return Ok(());
+            }
+            for (key, mut value) in std::mem::take(map) {
+                path.push(key);
+                walk(&mut value, case, depth_limit, exclusions, path, apply)?;
+                let key = path.pop().unwrap_or_default();
+                map.insert(case.convert(&key), value);
+            }
+            Ok(())
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter_mut().enumerate() {
+                path.push(i.to_string());
+                walk(value, case, depth_limit, exclusions, path, apply)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
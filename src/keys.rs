@@ -0,0 +1,108 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Shared parts of key conversion in structured documents.
+
+use std::{collections::HashMap, error, fmt};
+
+use crate::Case;
+
+/// Error returned when two keys of the same object are converted into
+/// the same key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyCollision {
+    path: String,
+    keys: (String, String),
+    converted: String,
+}
+
+impl KeyCollision {
+    /// Returns the JSON Pointer of the object with colliding keys.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns both original keys that are converted into the same key.
+    pub fn keys(&self) -> (&str, &str) {
+        (&self.keys.0, &self.keys.1)
+    }
+
+    /// Returns the key into which both original keys are converted.
+    pub fn converted(&self) -> &str {
+        &self.converted
+    }
+}
+
+impl fmt::Display for KeyCollision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "keys `{}` and `{}`", self.keys.0, self.keys.1)?;
+        if !self.path.is_empty() {
+            write!(f, " at `{}`", self.path)?;
+        }
+        write!(f, " are both converted to `{}`", self.converted)
+    }
+}
+
+impl error::Error for KeyCollision {}
+
+/// Paths of values which keys are not converted, parsed from JSON
+/// Pointers. The leading slash is optional and the `*` segment matches
+/// any key or array index.
+pub(crate) struct Exclusions {
+    paths: Vec<Vec<String>>,
+}
+
+impl Exclusions {
+    pub(crate) fn new(pointers: &[&str]) -> Self {
+        Exclusions {
+            paths: pointers
+                .iter()
+                .map(|p| {
+                    let p = p.strip_prefix('/').unwrap_or(p);
+                    if p.is_empty() {
+                        return Vec::new();
+                    }
+                    p.split('/')
+                        .map(|s| s.replace("~1", "/").replace("~0", "~"))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn matches(&self, path: &[String]) -> bool {
+        self.paths
+            .iter()
+            .any(|p| p.len() == path.len() && p.iter().zip(path).all(|(p, s)| p == "*" || p == s))
+    }
+}
+
+/// Returns the JSON Pointer of the path.
+pub(crate) fn pointer(path: &[String]) -> String {
+    path.iter()
+        .map(|s| format!("/{}", s.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Returns an error if any two keys are converted into the same key.
+pub(crate) fn check_collisions<'a>(
+    case: Case,
+    keys: impl Iterator<Item = &'a str>,
+    path: &[String],
+) -> Result<(), KeyCollision> {
+    let mut converted: HashMap<String, &str> = HashMap::new();
+    for key in keys {
+        let c = case.convert(key);
+        if let Some(other) = converted.get(&c) {
+            return Err(KeyCollision {
+                path: pointer(path),
+                keys: (other.to_string(), key.to_string()),
+                converted: c,
+            });
+        }
+        converted.insert(c, key);
+    }
+    Ok(())
+}
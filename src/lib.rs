@@ -24,7 +24,8 @@
 //! - `snake("camelSNAKEKebab")` returns `camel_snake_kebab`
 //!
 //! Word separation works by detecting delimiters hyphen (-),
-//! underscore (_), space ( ) and letter case change.
+//! underscore (_), space ( ) and letter case change. Separated
+//! words are returned by the [`words`] iterator.
 //!
 //! Note: Leading and trailing separators will be preserved
 //! only within the Snake family or within the Kebab family
@@ -39,8 +40,55 @@
 //! - `camel_kebab("--camel-snake-kebab")` returns `--Camel-Snake-Kebab`
 //! - `snake("--camel-snake-kebab")` returns `camel_snake_kebab`
 //! - `screaming("--camel-snake-kebab")` returns `CAMEL SNAKE KEBAB`
+//!
+//! The library supports `no_std` environments with the `alloc`
+//! crate when the default `std` feature is disabled.
+//!
+//! String literals can be converted at compile time with macros
+//! such as [`snake_lit`], for example `snake_lit!("HttpServerURL")`
+//! returns `http_server_url` as a `&'static str` constant. These
+//! macros support only ASCII strings and fail to compile otherwise.
+//!
+//! The [`env`] module converts nested configuration paths
+//! into environment variable names, such as `database.pool.maxSize`
+//! into `APP_DATABASE__POOL__MAX_SIZE`, and back.
+//!
+//! Conversion loses the letter case of words and the original separators.
+//! The [`reversible`] module records them in a compact
+//! hint, so that `HTTPServer` can be restored from `http_server`.
+//!
+//! Optional `serde` feature enables the [`serde`](crate::serde) module
+//! for renaming struct fields and map keys during serialization.
+//! Optional `json` feature enables the [`json`](crate::json) module for
+//! converting keys of JSON objects, and optional `toml` and `yaml`
+//! features enable the [`toml`](crate::toml) and [`yaml`](crate::yaml)
+//! modules for converting keys of TOML and YAML documents.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
-use std::{fmt::Write, usize};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+mod batch;
+pub mod env;
+mod ext;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+mod keys;
+#[doc(hidden)]
+pub mod lit;
+pub mod reversible;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "toml")]
+pub mod toml;
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
+pub use ext::CasbabExt;
 
 /// *Camel* case is the practice of writing compound words
 /// or phrases such that each word or abbreviation in the
@@ -49,7 +97,7 @@ use std::{fmt::Write, usize};
 ///
 /// Example: `camelSnakeKebab`.
 pub fn camel(s: &str) -> String {
-    casbab(s, to_titlecase, to_lowercase)
+    Case::Camel.convert(s)
 }
 
 /// *Pascal* case is a variant of Camel case writing where
@@ -57,7 +105,7 @@ pub fn camel(s: &str) -> String {
 ///
 /// Example: `CamelSnakeKebab`.
 pub fn pascal(s: &str) -> String {
-    casbab(s, to_titlecase, to_titlecase)
+    Case::Pascal.convert(s)
 }
 
 /// *Snake* case is the practice of writing compound words
@@ -67,7 +115,7 @@ pub fn pascal(s: &str) -> String {
 ///
 /// Example: `camel_snake_kebab`.
 pub fn snake(s: &str) -> String {
-    casbab_wrap(s, '_', to_lowercase)
+    Case::Snake.convert(s)
 }
 
 /// *Camel snake* case is a variant of Camel case with
@@ -75,7 +123,7 @@ pub fn snake(s: &str) -> String {
 ///
 /// Example: `Camel_Snake_Kebab`.
 pub fn camel_snake(s: &str) -> String {
-    casbab_wrap(s, '_', to_titlecase)
+    Case::CamelSnake.convert(s)
 }
 
 /// *Screaming snake* case is a variant of Camel case with
@@ -83,7 +131,7 @@ pub fn camel_snake(s: &str) -> String {
 ///
 /// Example: `CAMEL_SNAKE_KEBAB`.
 pub fn screaming_snake(s: &str) -> String {
-    casbab_wrap(s, '_', to_uppercase)
+    Case::ScreamingSnake.convert(s)
 }
 
 /// *Kebab* case is the practice of writing compound words
@@ -93,7 +141,7 @@ pub fn screaming_snake(s: &str) -> String {
 ///
 /// Example: `camel-snake-kebab`.
 pub fn kebab(s: &str) -> String {
-    casbab_wrap(s, '-', to_lowercase)
+    Case::Kebab.convert(s)
 }
 
 /// *Camel kebab* case is a variant of Kebab case with
@@ -101,7 +149,7 @@ pub fn kebab(s: &str) -> String {
 ///
 /// Example: `Camel-Snake-Kebab`.
 pub fn camel_kebab(s: &str) -> String {
-    casbab_wrap(s, '-', to_titlecase)
+    Case::CamelKebab.convert(s)
 }
 
 /// *Screaming kebab* case is a variant of Kebab case with
@@ -109,7 +157,7 @@ pub fn camel_kebab(s: &str) -> String {
 ///
 /// Example: `CAMEL-SNAKE-KEBAB`.
 pub fn screaming_kebab(s: &str) -> String {
-    casbab_wrap(s, '-', to_uppercase)
+    Case::ScreamingKebab.convert(s)
 }
 
 /// *Lower* is returning detected words, not in a compound
@@ -118,7 +166,7 @@ pub fn screaming_kebab(s: &str) -> String {
 ///
 /// Example: `camel snake kebab`.
 pub fn lower(s: &str) -> String {
-    casbab_separate(s, ' ', to_lowercase)
+    Case::Lower.convert(s)
 }
 
 /// *Title* is returning detected words, not in a compound
@@ -128,7 +176,7 @@ pub fn lower(s: &str) -> String {
 ///
 /// Example: `Camel Snake Kebab`.
 pub fn title(s: &str) -> String {
-    casbab_separate(s, ' ', to_titlecase)
+    Case::Title.convert(s)
 }
 
 /// *Screaming* is returning detected words, not in a compound
@@ -137,72 +185,431 @@ pub fn title(s: &str) -> String {
 ///
 /// Example: `CAMEL SNAKE KEBAB`.
 pub fn screaming(s: &str) -> String {
-    casbab_separate(s, ' ', to_uppercase)
+    Case::Screaming.convert(s)
+}
+
+/// Case is one of the writing styles of compound words or phrases
+/// that this package converts to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Example: `camelSnakeKebab`.
+    Camel,
+    /// Example: `CamelSnakeKebab`.
+    Pascal,
+    /// Example: `camel_snake_kebab`.
+    Snake,
+    /// Example: `Camel_Snake_Kebab`.
+    CamelSnake,
+    /// Example: `CAMEL_SNAKE_KEBAB`.
+    ScreamingSnake,
+    /// Example: `camel-snake-kebab`.
+    Kebab,
+    /// Example: `Camel-Snake-Kebab`.
+    CamelKebab,
+    /// Example: `CAMEL-SNAKE-KEBAB`.
+    ScreamingKebab,
+    /// Example: `camel snake kebab`.
+    Lower,
+    /// Example: `Camel Snake Kebab`.
+    Title,
+    /// Example: `CAMEL SNAKE KEBAB`.
+    Screaming,
 }
 
-fn casbab(
+impl Case {
+    /// All cases, in the same order as the conversion functions.
+    pub const ALL: [Case; 11] = [
+        Case::Camel,
+        Case::Pascal,
+        Case::Snake,
+        Case::CamelSnake,
+        Case::ScreamingSnake,
+        Case::Kebab,
+        Case::CamelKebab,
+        Case::ScreamingKebab,
+        Case::Lower,
+        Case::Title,
+        Case::Screaming,
+    ];
+
+    /// Returns the name of the case in *Kebab* case, as it is used by the
+    /// command line tool and parsed by the `FromStr` implementation.
+    ///
+    /// Example: `Case::ScreamingSnake.name()` returns `screaming-snake`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Case::Camel => "camel",
+            Case::Pascal => "pascal",
+            Case::Snake => "snake",
+            Case::CamelSnake => "camel-snake",
+            Case::ScreamingSnake => "screaming-snake",
+            Case::Kebab => "kebab",
+            Case::CamelKebab => "camel-kebab",
+            Case::ScreamingKebab => "screaming-kebab",
+            Case::Lower => "lower",
+            Case::Title => "title",
+            Case::Screaming => "screaming",
+        }
+    }
+
+    /// Converts the phrase into this case. It is equivalent to calling
+    /// the function with the same name, for example `Case::Snake.convert(s)`
+    /// returns the same value as `snake(s)`.
+    pub fn convert(self, s: &str) -> String {
+        let mut r = String::with_capacity(s.len());
+        _ = self.write(&mut r, s);
+        r
+    }
+
+    /// Writes the phrase converted into this case directly to the
+    /// writer, without allocating an intermediate string.
+    ///
+    /// Example: `Case::Snake.write(&mut w, "HttpServer")` writes
+    /// `http_server`.
+    pub fn write<W: Write>(self, w: &mut W, s: &str) -> fmt::Result {
+        match self {
+            Case::Camel => casbab(w, s, to_titlecase, to_lowercase),
+            Case::Pascal => casbab(w, s, to_titlecase, to_titlecase),
+            Case::Snake => casbab_wrap(w, s, '_', to_lowercase),
+            Case::CamelSnake => casbab_wrap(w, s, '_', to_titlecase),
+            Case::ScreamingSnake => casbab_wrap(w, s, '_', to_uppercase),
+            Case::Kebab => casbab_wrap(w, s, '-', to_lowercase),
+            Case::CamelKebab => casbab_wrap(w, s, '-', to_titlecase),
+            Case::ScreamingKebab => casbab_wrap(w, s, '-', to_uppercase),
+            Case::Lower => casbab_separate(w, s, ' ', to_lowercase),
+            Case::Title => casbab_separate(w, s, ' ', to_titlecase),
+            Case::Screaming => casbab_separate(w, s, ' ', to_uppercase),
+        }
+    }
+
+    /// Returns true if the phrase is already written in this case, that is
+    /// if converting it would not change it.
+    ///
+    /// Example: `Case::Snake.matches("http_server")` returns `true`.
+    pub fn matches(self, s: &str) -> bool {
+        let mut m = Matcher { rest: s };
+        self.write(&mut m, s).is_ok() && m.rest.is_empty()
+    }
+
+    /// Returns a value that formats the phrase converted into this case
+    /// when it is displayed, so that it can be used in `format!` or
+    /// `write!` without allocating an intermediate string.
+    ///
+    /// Example: `format!("{}", Case::Kebab.display("HttpServer"))` returns
+    /// `http-server`.
+    pub fn display(self, s: &str) -> CaseDisplay<'_> {
+        CaseDisplay { case: self, s }
+    }
+}
+
+impl core::str::FromStr for Case {
+    type Err = UnknownNameError;
+
+    /// Parses the name returned by [`Case::name`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Case::ALL
+            .into_iter()
+            .find(|case| case.name() == s)
+            .ok_or_else(|| UnknownNameError::new(s))
+    }
+}
+
+/// Lazily converted phrase returned by [`Case::display`]. Formatting
+/// options, such as width or fill, are ignored.
+#[derive(Debug, Clone, Copy)]
+pub struct CaseDisplay<'a> {
+    case: Case,
+    s: &'a str,
+}
+
+impl fmt::Display for CaseDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.case.write(f, self.s)
+    }
+}
+
+/// Writer that fails as soon as the written data diverges from the
+/// expected string.
+struct Matcher<'a> {
+    rest: &'a str,
+}
+
+impl Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.rest.strip_prefix(s) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+macro_rules! display_adapter {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<'a>(pub &'a str);
+
+        impl fmt::Display for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Case::$name.write(f, self.0)
+            }
+        }
+    };
+}
+
+display_adapter!(
+    /// Displays the phrase in *Camel* case, for example
+    /// `format!("{}", Camel("camel_snake_kebab"))` returns `camelSnakeKebab`.
+    Camel
+);
+display_adapter!(
+    /// Displays the phrase in *Pascal* case, for example
+    /// `format!("{}", Pascal("camel_snake_kebab"))` returns `CamelSnakeKebab`.
+    Pascal
+);
+display_adapter!(
+    /// Displays the phrase in *Snake* case, for example
+    /// `format!("{}", Snake("camelSnakeKebab"))` returns `camel_snake_kebab`.
+    Snake
+);
+display_adapter!(
+    /// Displays the phrase in *Camel snake* case, for example
+    /// `format!("{}", CamelSnake("camelSnakeKebab"))` returns `Camel_Snake_Kebab`.
+    CamelSnake
+);
+display_adapter!(
+    /// Displays the phrase in *Screaming snake* case, for example
+    /// `format!("{}", ScreamingSnake("camelSnakeKebab"))` returns `CAMEL_SNAKE_KEBAB`.
+    ScreamingSnake
+);
+display_adapter!(
+    /// Displays the phrase in *Kebab* case, for example
+    /// `format!("{}", Kebab("camelSnakeKebab"))` returns `camel-snake-kebab`.
+    Kebab
+);
+display_adapter!(
+    /// Displays the phrase in *Camel kebab* case, for example
+    /// `format!("{}", CamelKebab("camelSnakeKebab"))` returns `Camel-Snake-Kebab`.
+    CamelKebab
+);
+display_adapter!(
+    /// Displays the phrase in *Screaming kebab* case, for example
+    /// `format!("{}", ScreamingKebab("camelSnakeKebab"))` returns `CAMEL-SNAKE-KEBAB`.
+    ScreamingKebab
+);
+display_adapter!(
+    /// Displays the phrase in *Lower* case, for example
+    /// `format!("{}", Lower("camelSnakeKebab"))` returns `camel snake kebab`.
+    Lower
+);
+display_adapter!(
+    /// Displays the phrase in *Title* case, for example
+    /// `format!("{}", Title("camelSnakeKebab"))` returns `Camel Snake Kebab`.
+    Title
+);
+display_adapter!(
+    /// Displays the phrase in *Screaming* case, for example
+    /// `format!("{}", Screaming("camelSnakeKebab"))` returns `CAMEL SNAKE KEBAB`.
+    Screaming
+);
+
+/// Error returned when a string does not match any of the known names,
+/// for example by the `FromStr` implementation generated by the
+/// `CaseNames` derive macro from the `casbab-derive` crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownNameError {
+    name: String,
+}
+
+impl UnknownNameError {
+    /// Creates the error for the name that was not matched.
+    pub fn new(name: &str) -> Self {
+        UnknownNameError { name: name.into() }
+    }
+
+    /// Returns the name that was not matched.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for UnknownNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown name `{}`", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownNameError {}
+
+/// Converts every phrase from the batch into the same case.
+///
+/// Bytes of ASCII phrases, which are the most common in column or field
+/// names, are classified eight at a time in a machine word, and word
+/// boundaries are found from the resulting bit masks, instead of one byte
+/// at a time. Other phrases are converted in the same way as by
+/// [`Case::convert`].
+///
+/// Example: `convert_many(Case::Snake, &["fooBar", "BazQux"])` returns
+/// `["foo_bar", "baz_qux"]`.
+pub fn convert_many(case: Case, phrases: &[&str]) -> Vec<String> {
+    let mut masks = batch::Masks::default();
+    phrases
+        .iter()
+        .map(|s| {
+            let mut converted = String::with_capacity(s.len());
+            write_batched(case, s, &mut converted, &mut masks);
+            converted
+        })
+        .collect()
+}
+
+/// Converts every phrase from the batch into the same case, appending
+/// results to a single buffer instead of allocating a string for each
+/// of them. The end offset of every converted phrase in the buffer is
+/// appended to offsets, so that the phrase with index `i` is
+/// `buf[start..offsets[i]]`, where start is `offsets[i - 1]` or the
+/// length of the buffer before the call for the first phrase.
+///
+/// Buffers can be reused between calls to amortise allocations.
+pub fn convert_many_into(case: Case, phrases: &[&str], buf: &mut String, offsets: &mut Vec<usize>) {
+    buf.reserve(phrases.iter().map(|s| s.len()).sum());
+    offsets.reserve(phrases.len());
+    let mut masks = batch::Masks::default();
+    for s in phrases {
+        write_batched(case, s, buf, &mut masks);
+        offsets.push(buf.len());
+    }
+}
+
+fn write_batched(case: Case, s: &str, buf: &mut String, masks: &mut batch::Masks) {
+    if s.is_ascii() {
+        batch::write(case, s, buf, masks);
+    } else {
+        _ = case.write(buf, s);
+    }
+}
+
+/// Returns all cases in which the phrase is already written, in the
+/// order of [`Case::ALL`]. The result is empty if the phrase mixes
+/// different styles, and it has more than one case if the style is
+/// ambiguous, which is always true for phrases with a single word.
+///
+/// Example: `detect("http_server")` returns `[Case::Snake]`, while
+/// `detect("server")` returns `[Case::Camel, Case::Snake, Case::Kebab,
+/// Case::Lower]`.
+pub fn detect(s: &str) -> Vec<Case> {
+    Case::ALL
+        .into_iter()
+        .filter(|case| case.matches(s))
+        .collect()
+}
+
+/// Returns an iterator over words of the phrase, separated in the same
+/// way as by the conversion functions. Words are substrings of the
+/// phrase and they never contain separators.
+///
+/// Example: `words("camelSNAKE_kebab")` yields `camel`, `SNAKE` and
+/// `kebab`.
+pub fn words(s: &str) -> Words<'_> {
+    Words { rest: s }
+}
+
+/// Iterator over words of a phrase returned by [`words`].
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let (w, rest) = first_word(self.rest);
+        self.rest = rest;
+        if w.is_empty() {
+            None
+        } else {
+            Some(w)
+        }
+    }
+}
+
+impl core::iter::FusedIterator for Words<'_> {}
+
+fn casbab<W: Write>(
+    r: &mut W,
     s: &str,
-    transform: fn(&str) -> String,
-    transform_first_word: fn(&str) -> String,
-) -> String {
-    let mut r = String::new();
+    transform: fn(&mut W, &str) -> fmt::Result,
+    transform_first_word: fn(&mut W, &str) -> fmt::Result,
+) -> fmt::Result {
     let mut s = s;
     let (w, rest) = first_word(s);
-    r += &transform_first_word(w);
+    transform_first_word(r, w)?;
     s = rest;
     loop {
         let (w, rest) = first_word(s);
         if w.is_empty() {
-            break r;
+            break Ok(());
         }
-        r += &transform(w);
+        transform(r, w)?;
         if rest.is_empty() {
-            break r;
+            break Ok(());
         }
         s = rest;
     }
 }
 
-fn casbab_separate(s: &str, separator: char, transform: fn(&str) -> String) -> String {
-    let mut r = String::new();
+fn casbab_separate<W: Write>(
+    r: &mut W,
+    s: &str,
+    separator: char,
+    transform: fn(&mut W, &str) -> fmt::Result,
+) -> fmt::Result {
     let mut s = s;
     let (w, rest) = first_word(s);
-    r += &transform(w);
+    transform(r, w)?;
     s = rest;
     loop {
         let (w, rest) = first_word(s);
         if w.is_empty() {
-            break r;
+            break Ok(());
         }
-        _ = r.write_char(separator);
-        r += &transform(w);
+        r.write_char(separator)?;
+        transform(r, w)?;
         if rest.is_empty() {
-            break r;
+            break Ok(());
         }
         s = rest;
     }
 }
 
-fn casbab_wrap(s: &str, separator: char, transform: fn(&str) -> String) -> String {
-    let mut r = String::new();
-
+fn casbab_wrap<W: Write>(
+    r: &mut W,
+    s: &str,
+    separator: char,
+    transform: fn(&mut W, &str) -> fmt::Result,
+) -> fmt::Result {
     let (head, tail) = head_tail_count(s, separator);
 
     for _ in 0..head {
-        _ = r.write_char(separator);
+        r.write_char(separator)?;
     }
 
     let mut s = s;
     let (w, rest) = first_word(s);
-    r += &transform(w);
+    transform(r, w)?;
     s = rest;
     loop {
         let (w, rest) = first_word(s);
         if w.is_empty() {
             break;
         }
-        _ = r.write_char(separator);
-        r += &transform(w);
+        r.write_char(separator)?;
+        transform(r, w)?;
         if rest.is_empty() {
             break;
         }
@@ -210,72 +617,150 @@ fn casbab_wrap(s: &str, separator: char, transform: fn(&str) -> String) -> Strin
     }
 
     for _ in 0..tail {
-        _ = r.write_char(separator);
+        r.write_char(separator)?;
     }
 
-    r
+    Ok(())
 }
 
+/// Splits the first word from the rest of the string.
 fn first_word(s: &str) -> (&str, &str) {
-    let mut start: usize = 0;
-    let l = s.len();
+    let (start, end) = word_bounds(s.as_bytes(), 0);
+    (&s[start..end], &s[end..])
+}
+
+/// Returns the start and the end index of the first word found from the
+/// index, which are equal if there are no more words. ASCII bytes are
+/// classified directly and only other characters are decoded, so that
+/// ASCII input is split at the byte level without Unicode case lookups.
+/// It is a constant function, so that the same rules are also used for
+/// literals converted at compile time.
+pub(crate) const fn word_bounds(s: &[u8], from: usize) -> (usize, usize) {
+    let mut start = from;
     let mut prev_lower = false;
     let mut prev_upper = false;
-    let mut prev_upper_location: usize = 0;
+    let mut prev_upper_location = 0;
+
+    let mut i = from;
+    while i < s.len() {
+        let b = s[i];
+        let (separator, upper, len) = if b.is_ascii() {
+            (
+                b == b'-' || b == b'_' || b == b' ',
+                b.is_ascii_uppercase(),
+                1,
+            )
+        } else {
+            let (c, len) = decode_char(s, i);
+            (false, c.is_uppercase(), len)
+        };
 
-    for (i, c) in s.char_indices() {
-        if c == '-' || c == '_' || c == ' ' {
+        if separator {
             if start != i {
-                return (&s[start..i], &s[i..]);
-            };
+                return (start, i);
+            }
             start = i + 1;
             prev_lower = false;
             prev_upper = false;
             prev_upper_location = 0;
-            continue;
-        }
-
-        if c.is_uppercase() {
+        } else if upper {
             prev_upper = true;
             prev_upper_location = i;
             if prev_lower {
                 if start != i {
-                    return (&s[start..i], &s[i..]);
+                    return (start, i);
                 }
                 start = i;
                 prev_lower = false;
-            };
+            }
         } else {
             prev_lower = true;
             if prev_upper && prev_upper_location > 0 {
                 if start != prev_upper_location {
-                    return (&s[start..prev_upper_location], &s[prev_upper_location..]);
+                    return (start, prev_upper_location);
                 }
                 start = prev_upper_location;
                 prev_upper = false;
                 prev_upper_location = 0;
-            };
+            }
         }
+        i += len;
     }
-    if start != l {
-        return (&s[start..], "");
+    (start, s.len())
+}
+
+/// Decodes the character that starts at the index of the valid UTF-8
+/// string and returns it with its length in bytes.
+const fn decode_char(s: &[u8], i: usize) -> (char, usize) {
+    let b = s[i] as u32;
+    let (mut c, len) = if b >= 0xF0 {
+        (b & 0x07, 4)
+    } else if b >= 0xE0 {
+        (b & 0x0F, 3)
+    } else {
+        (b & 0x1F, 2)
+    };
+    let mut j = 1;
+    while j < len {
+        c = (c << 6) | (s[i + j] as u32 & 0x3F);
+        j += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => (c, len),
+        None => panic!("string is not valid UTF-8"),
     }
-    ("", "")
 }
 
-fn to_lowercase(s: &str) -> String {
-    s.to_lowercase()
+fn to_lowercase<W: Write>(r: &mut W, s: &str) -> fmt::Result {
+    if s.is_ascii() {
+        write_ascii(r, s, u8::to_ascii_lowercase)
+    } else {
+        r.write_str(&s.to_lowercase())
+    }
 }
-fn to_uppercase(s: &str) -> String {
-    s.to_uppercase()
+
+fn to_uppercase<W: Write>(r: &mut W, s: &str) -> fmt::Result {
+    if s.is_ascii() {
+        write_ascii(r, s, u8::to_ascii_uppercase)
+    } else {
+        r.write_str(&s.to_uppercase())
+    }
 }
 
-fn to_titlecase(s: &str) -> String {
+fn to_titlecase<W: Write>(r: &mut W, s: &str) -> fmt::Result {
+    if s.is_ascii() {
+        if s.is_empty() {
+            return Ok(());
+        }
+        let (f, rest) = s.split_at(1);
+        write_ascii(r, f, u8::to_ascii_uppercase)?;
+        return write_ascii(r, rest, u8::to_ascii_lowercase);
+    }
     let mut chars = s.chars();
     match chars.next() {
-        None => s.to_string(),
-        Some(f) => f.to_uppercase().to_string() + &chars.as_str().to_lowercase(),
+        None => Ok(()),
+        Some(f) => {
+            for c in f.to_uppercase() {
+                r.write_char(c)?;
+            }
+            r.write_str(&chars.as_str().to_lowercase())
+        }
+    }
+}
+
+/// Writes ASCII string with every byte mapped by the transform function.
+/// Bytes are transformed in chunks on the stack to avoid allocations.
+fn write_ascii<W: Write>(r: &mut W, s: &str, transform: fn(&u8) -> u8) -> fmt::Result {
+    let mut buf = [0u8; 64];
+    for chunk in s.as_bytes().chunks(buf.len()) {
+        let buf = &mut buf[..chunk.len()];
+        for (b, c) in buf.iter_mut().zip(chunk) {
+            *b = transform(c);
+        }
+        // ASCII bytes mapped to ASCII bytes are always valid UTF-8.
+        r.write_str(core::str::from_utf8(buf).map_err(|_| fmt::Error)?)?;
     }
+    Ok(())
 }
 
 fn head_tail_count(s: &str, sub: char) -> (usize, usize) {
@@ -0,0 +1,294 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Compile time conversion of string literals used by macros such as
+//! [`snake_lit`](crate::snake_lit). Functions in this module are not
+//! part of the public API.
+//!
+//! Words are split by the same function as at runtime, but conversion is
+//! limited to ASCII strings, as Unicode case mapping tables are not
+//! available in constant evaluation.
+
+use crate::{word_bounds, Case};
+
+#[derive(Clone, Copy)]
+pub(crate) enum Transform {
+    Lower,
+    Upper,
+    Title,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Layout {
+    /// Words are joined without separator, like in `casbab`.
+    Join,
+    /// Words are separated, like in `casbab_separate`.
+    Separate(u8),
+    /// Words are separated and leading and trailing separators are
+    /// preserved, like in `casbab_wrap`.
+    Wrap(u8),
+}
+
+/// Returns the layout of words and transforms of the first and of other
+/// words of the case.
+pub(crate) const fn spec(case: Case) -> (Layout, Transform, Transform) {
+    match case {
+        Case::Camel => (Layout::Join, Transform::Lower, Transform::Title),
+        Case::Pascal => (Layout::Join, Transform::Title, Transform::Title),
+        Case::Snake => (Layout::Wrap(b'_'), Transform::Lower, Transform::Lower),
+        Case::CamelSnake => (Layout::Wrap(b'_'), Transform::Title, Transform::Title),
+        Case::ScreamingSnake => (Layout::Wrap(b'_'), Transform::Upper, Transform::Upper),
+        Case::Kebab => (Layout::Wrap(b'-'), Transform::Lower, Transform::Lower),
+        Case::CamelKebab => (Layout::Wrap(b'-'), Transform::Title, Transform::Title),
+        Case::ScreamingKebab => (Layout::Wrap(b'-'), Transform::Upper, Transform::Upper),
+        Case::Lower => (Layout::Separate(b' '), Transform::Lower, Transform::Lower),
+        Case::Title => (Layout::Separate(b' '), Transform::Title, Transform::Title),
+        Case::Screaming => (Layout::Separate(b' '), Transform::Upper, Transform::Upper),
+    }
+}
+
+/// Returns the length of the converted string.
+pub const fn len(case: Case, s: &str) -> usize {
+    convert::<0>(case, s).1
+}
+
+/// Returns the converted string bytes and their length. Bytes that do
+/// not fit into the array are only counted.
+pub const fn convert<const N: usize>(case: Case, s: &str) -> ([u8; N], usize) {
+    let s = s.as_bytes();
+    let mut i = 0;
+    while i < s.len() {
+        if !s[i].is_ascii() {
+            panic!("casbab literal macros support only ASCII strings");
+        }
+        i += 1;
+    }
+
+    let (layout, transform_first_word, transform) = spec(case);
+    let mut out = [0u8; N];
+    let mut n = 0;
+
+    let (head, tail) = match layout {
+        Layout::Wrap(separator) => head_tail_count(s, separator),
+        _ => (0, 0),
+    };
+    let mut i = 0;
+    while i < head {
+        (out, n) = push(out, n, s[0]);
+        i += 1;
+    }
+
+    let mut from = 0;
+    let mut first = true;
+    loop {
+        let (start, end) = word_bounds(s, from);
+        if start == end {
+            break;
+        }
+        if !first {
+            match layout {
+                Layout::Separate(separator) | Layout::Wrap(separator) => {
+                    (out, n) = push(out, n, separator);
+                }
+                Layout::Join => {}
+            }
+        }
+        let transform = if first {
+            transform_first_word
+        } else {
+            transform
+        };
+        let mut i = start;
+        while i < end {
+            let b = match transform {
+                Transform::Lower => s[i].to_ascii_lowercase(),
+                Transform::Upper => s[i].to_ascii_uppercase(),
+                Transform::Title if i == start => s[i].to_ascii_uppercase(),
+                Transform::Title => s[i].to_ascii_lowercase(),
+            };
+            (out, n) = push(out, n, b);
+            i += 1;
+        }
+        first = false;
+        from = end;
+    }
+
+    let mut i = 0;
+    while i < tail {
+        (out, n) = push(out, n, s[s.len() - 1]);
+        i += 1;
+    }
+
+    (out, n)
+}
+
+/// Returns the converted string from the array returned by `convert`.
+pub const fn as_str<const N: usize>(b: &([u8; N], usize)) -> &str {
+    match core::str::from_utf8(&b.0) {
+        Ok(s) => s,
+        Err(_) => panic!("casbab literal is not valid UTF-8"),
+    }
+}
+
+const fn push<const N: usize>(mut out: [u8; N], n: usize, b: u8) -> ([u8; N], usize) {
+    if n < N {
+        out[n] = b;
+    }
+    (out, n + 1)
+}
+
+const fn head_tail_count(s: &[u8], sub: u8) -> (usize, usize) {
+    let mut head = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] != sub {
+            head = i;
+            break;
+        }
+        i += 1;
+    }
+    let mut tail = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if s[s.len() - 1 - i] != sub {
+            tail = i;
+            break;
+        }
+        i += 1;
+    }
+    (head, tail)
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __case_lit {
+    ($case:expr, $s:expr) => {{
+        const N: usize = $crate::lit::len($case, $s);
+        const B: ([u8; N], usize) = $crate::lit::convert::<N>($case, $s);
+        const S: &str = $crate::lit::as_str(&B);
+        S
+    }};
+}
+
+/// Converts the ASCII string literal into *Camel* case at compile time.
+///
+/// Example: `camel_lit!("camel_snake_kebab")` returns `camelSnakeKebab`.
+#[macro_export]
+macro_rules! camel_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::Camel, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Pascal* case at compile time.
+///
+/// Example: `pascal_lit!("camel_snake_kebab")` returns `CamelSnakeKebab`.
+#[macro_export]
+macro_rules! pascal_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::Pascal, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Snake* case at compile time.
+///
+/// Example: `snake_lit!("HttpServerURL")` returns `http_server_url`.
+///
+/// Strings that are not ASCII fail to compile:
+///
+/// ```compile_fail
+/// const NAME: &str = casbab::snake_lit!("Ово је Brave NewСвет");
+/// ```
+#[macro_export]
+macro_rules! snake_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::Snake, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Camel snake* case at compile
+/// time.
+///
+/// Example: `camel_snake_lit!("camelSnakeKebab")` returns `Camel_Snake_Kebab`.
+#[macro_export]
+macro_rules! camel_snake_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::CamelSnake, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Screaming snake* case at
+/// compile time.
+///
+/// Example: `screaming_snake_lit!("camelSnakeKebab")` returns `CAMEL_SNAKE_KEBAB`.
+#[macro_export]
+macro_rules! screaming_snake_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::ScreamingSnake, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Kebab* case at compile time.
+///
+/// Example: `kebab_lit!("camelSnakeKebab")` returns `camel-snake-kebab`.
+#[macro_export]
+macro_rules! kebab_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::Kebab, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Camel kebab* case at compile
+/// time.
+///
+/// Example: `camel_kebab_lit!("camelSnakeKebab")` returns `Camel-Snake-Kebab`.
+#[macro_export]
+macro_rules! camel_kebab_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::CamelKebab, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Screaming kebab* case at
+/// compile time.
+///
+/// Example: `screaming_kebab_lit!("camelSnakeKebab")` returns `CAMEL-SNAKE-KEBAB`.
+#[macro_export]
+macro_rules! screaming_kebab_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::ScreamingKebab, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Lower* case at compile time.
+///
+/// Example: `lower_lit!("camelSnakeKebab")` returns `camel snake kebab`.
+#[macro_export]
+macro_rules! lower_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::Lower, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Title* case at compile time.
+///
+/// Example: `title_lit!("camelSnakeKebab")` returns `Camel Snake Kebab`.
+#[macro_export]
+macro_rules! title_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::Title, $s)
+    };
+}
+
+/// Converts the ASCII string literal into *Screaming* case at compile
+/// time.
+///
+/// Example: `screaming_lit!("camelSnakeKebab")` returns `CAMEL SNAKE KEBAB`.
+#[macro_export]
+macro_rules! screaming_lit {
+    ($s:expr) => {
+        $crate::__case_lit!($crate::Case::Screaming, $s)
+    };
+}
@@ -0,0 +1,284 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Reversible conversion that records information lost by the
+//! conversion in a compact hint, so that the original phrase can be
+//! restored from the converted one.
+//!
+//! ```
+//! use casbab::{reversible, Case};
+//!
+//! let (snake, hint) = reversible::convert(Case::Snake, "HTTPServer");
+//! assert_eq!(snake, "http_server");
+//! assert_eq!(hint, "ut");
+//! assert_eq!(reversible::restore(Case::Snake, &snake, &hint).unwrap(), "HTTPServer");
+//! ```
+//!
+//! The converted phrase is the same as the one returned by
+//! [`Case::convert`], so it can be passed through systems that accept
+//! only one style, while the hint is stored on the side.
+//!
+//! The hint describes every word of the original phrase in order. A word
+//! is preceded by the separators that were in front of it in the
+//! original phrase, `-` and `_` as they are and `.` in place of a space,
+//! and it is described by its letter case relative to the converted word:
+//! `l` for lower case, `u` for upper case, `t` for title case, or `x`
+//! followed by the length in bytes, a colon and the original word, when
+//! the case mapping of the word is not reversible. The hint ends with the
+//! trailing separators of the original phrase.
+//!
+//! Words of *Camel* and *Pascal* case converted phrases are found by
+//! splitting the converted phrase again. When that does not give the
+//! original words, for example with `x 2fa` converted to `X2fa`, every
+//! word is prefixed with its length in characters in the converted
+//! phrase.
+
+use crate::{first_word, to_lowercase, to_titlecase, to_uppercase, Case};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+/// Converts the phrase into the case and returns the converted phrase
+/// with the hint that is needed to restore the original phrase.
+///
+/// Example: `convert(Case::Snake, "Customer ID")` returns
+/// `("customer_id", "t.u")`.
+pub fn convert(case: Case, s: &str) -> (String, String) {
+    let converted = case.convert(s);
+
+    let mut words = Vec::new();
+    let mut rest = s;
+    loop {
+        let (w, tail) = first_word(rest);
+        if w.is_empty() {
+            break;
+        }
+        let start = offset(s, w);
+        let separators = &s[s.len() - rest.len()..start];
+        let word_case = if words.is_empty() || case != Case::Camel {
+            case
+        } else {
+            Case::Pascal
+        };
+        words.push((separators, w, word_case.convert(w)));
+        rest = tail;
+    }
+
+    let lengths = separator(case).is_none()
+        && !split_joined(&converted, None).eq(words.iter().map(|(_, _, c)| c.as_str()));
+
+    let mut hint = String::new();
+    for (separators, word, converted_word) in &words {
+        push_separators(&mut hint, separators);
+        if lengths {
+            let _ = write!(hint, "{}", converted_word.chars().count());
+        }
+        let code = [
+            (
+                'l',
+                to_lowercase::<String> as fn(&mut String, &str) -> fmt::Result,
+            ),
+            ('u', to_uppercase),
+            ('t', to_titlecase),
+        ]
+        .into_iter()
+        .find(|(_, transform)| transformed(*transform, converted_word) == *word);
+        match code {
+            Some((code, _)) => hint.push(code),
+            None => {
+                let _ = write!(hint, "x{}:{}", word.len(), word);
+            }
+        }
+    }
+    push_separators(&mut hint, &s[s.len() - rest.len()..]);
+
+    (converted, hint)
+}
+
+/// Restores the original phrase from the phrase converted into the case
+/// and the hint returned by [`convert`].
+///
+/// Example: `restore(Case::Snake, "customer_id", "t.u")` returns
+/// `Customer ID`.
+pub fn restore(case: Case, converted: &str, hint: &str) -> Result<String, HintError> {
+    let (words, trailing) = parse(hint)?;
+
+    let lengths = match words.iter().any(|w| w.length.is_some()) {
+        true => Some(
+            words
+                .iter()
+                .map(|w| w.length.ok_or(HintError))
+                .collect::<Result<Vec<usize>, HintError>>()?,
+        ),
+        false => None,
+    };
+    let converted_words: Vec<&str> = match separator(case) {
+        Some(separator) => {
+            let trimmed = converted.trim_matches(separator);
+            if trimmed.is_empty() {
+                Vec::new()
+            } else {
+                trimmed.split(separator).collect()
+            }
+        }
+        None => split_joined(converted, lengths.as_deref()).collect(),
+    };
+    let lengths_match = lengths.as_ref().is_none_or(|lengths| {
+        lengths
+            .iter()
+            .copied()
+            .eq(converted_words.iter().map(|w| w.chars().count()))
+    });
+    if converted_words.len() != words.len()
+        || !lengths_match
+        || (separator(case).is_none() && converted_words.concat() != converted)
+    {
+        return Err(HintError);
+    }
+
+    let mut s = String::new();
+    for (word, converted_word) in words.iter().zip(converted_words) {
+        s.push_str(&word.separators);
+        let _ = match word.code {
+            Code::Lower => to_lowercase(&mut s, converted_word),
+            Code::Upper => to_uppercase(&mut s, converted_word),
+            Code::Title => to_titlecase(&mut s, converted_word),
+            Code::Literal(literal) => s.write_str(literal),
+        };
+    }
+    s.push_str(&trailing);
+    Ok(s)
+}
+
+/// Error returned by [`restore`] when the hint is not valid or it does
+/// not match the converted phrase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HintError;
+
+impl fmt::Display for HintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid reversible conversion hint")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HintError {}
+
+enum Code<'a> {
+    Lower,
+    Upper,
+    Title,
+    Literal(&'a str),
+}
+
+struct Word<'a> {
+    separators: String,
+    length: Option<usize>,
+    code: Code<'a>,
+}
+
+fn parse(hint: &str) -> Result<(Vec<Word<'_>>, String), HintError> {
+    let mut words = Vec::new();
+    let mut separators = String::new();
+    let mut rest = hint;
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            '-' | '_' => separators.push(c),
+            '.' => separators.push(' '),
+            _ => {
+                let mut length = None;
+                let mut c = c;
+                if c.is_ascii_digit() {
+                    let (n, tail) = number(c, rest)?;
+                    length = Some(n);
+                    c = tail.chars().next().ok_or(HintError)?;
+                    rest = &tail[c.len_utf8()..];
+                }
+                let code = match c {
+                    'l' => Code::Lower,
+                    'u' => Code::Upper,
+                    't' => Code::Title,
+                    'x' => {
+                        let first = rest.chars().next().ok_or(HintError)?;
+                        let (n, tail) = number(first, &rest[first.len_utf8()..])?;
+                        let tail = tail.strip_prefix(':').ok_or(HintError)?;
+                        let literal = tail.get(..n).ok_or(HintError)?;
+                        rest = &tail[n..];
+                        Code::Literal(literal)
+                    }
+                    _ => return Err(HintError),
+                };
+                words.push(Word {
+                    separators: core::mem::take(&mut separators),
+                    length,
+                    code,
+                });
+            }
+        }
+    }
+    Ok((words, separators))
+}
+
+/// Parses the decimal number that starts with the digit and returns it
+/// with the rest of the string.
+fn number(first: char, rest: &str) -> Result<(usize, &str), HintError> {
+    let mut n = first.to_digit(10).ok_or(HintError)? as usize;
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    for b in rest[..digits].bytes() {
+        n = n
+            .checked_mul(10)
+            .and_then(|n| n.checked_add(usize::from(b - b'0')))
+            .ok_or(HintError)?;
+    }
+    Ok((n, &rest[digits..]))
+}
+
+/// Splits the phrase with words joined without a separator, either by
+/// the lengths of the words in characters or by the casbab rules.
+fn split_joined<'a>(
+    s: &'a str,
+    lengths: Option<&'a [usize]>,
+) -> impl Iterator<Item = &'a str> + 'a {
+    let mut rest = s;
+    let mut lengths = lengths.map(|l| l.iter());
+    core::iter::from_fn(move || match &mut lengths {
+        Some(lengths) => {
+            let n = *lengths.next()?;
+            let end = rest.char_indices().nth(n).map_or(rest.len(), |(i, _)| i);
+            let (w, tail) = rest.split_at(end);
+            rest = tail;
+            Some(w)
+        }
+        None => {
+            let (w, tail) = first_word(rest);
+            rest = tail;
+            (!w.is_empty()).then_some(w)
+        }
+    })
+}
+
+fn push_separators(hint: &mut String, separators: &str) {
+    hint.extend(separators.chars().map(|c| if c == ' ' { '.' } else { c }));
+}
+
+fn transformed(transform: fn(&mut String, &str) -> fmt::Result, s: &str) -> String {
+    let mut r = String::new();
+    let _ = transform(&mut r, s);
+    r
+}
+
+/// Returns the byte offset of the substring within the string.
+fn offset(s: &str, sub: &str) -> usize {
+    sub.as_ptr() as usize - s.as_ptr() as usize
+}
+
+fn separator(case: Case) -> Option<char> {
+    match case {
+        Case::Camel | Case::Pascal => None,
+        Case::Snake | Case::CamelSnake | Case::ScreamingSnake => Some('_'),
+        Case::Kebab | Case::CamelKebab | Case::ScreamingKebab => Some('-'),
+        Case::Lower | Case::Title | Case::Screaming => Some(' '),
+    }
+}
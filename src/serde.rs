@@ -0,0 +1,863 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Renaming of struct fields and map keys with casbab styles during
+//! serialization and deserialization.
+//!
+//! Serde attribute `rename_all` splits words with its own rules, which
+//! differ from the casbab ones, for example for acronyms. Types in this
+//! module apply casbab conversion to field names of all nested structs
+//! and to string keys of all nested maps.
+//!
+//! A value can be wrapped into the [`Renamed`] type:
+//!
+//! ```
+//! use casbab::serde::{Camel, Renamed};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct User {
+//!     user_id: u64,
+//!     home_url: String,
+//! }
+//!
+//! let user = User { user_id: 1, home_url: "https://example.com".to_string() };
+//! let json = serde_json::to_string(&Renamed::<Camel, _>::new(user)).unwrap();
+//! assert_eq!(json, r#"{"userId":1,"homeUrl":"https://example.com"}"#);
+//!
+//! let user: Renamed<Camel, User> = serde_json::from_str(&json).unwrap();
+//! assert_eq!(user.user_id, 1);
+//! ```
+//!
+//! Or a field can be annotated to use one of the modules named after
+//! the styles, such as [`camel`] or [`screaming_snake`]:
+//!
+//! ```
+//! #[derive(serde::Serialize)]
+//! struct Request {
+//!     #[serde(with = "casbab::serde::camel")]
+//!     body: Body,
+//! }
+//!
+//! #[derive(serde::Serialize)]
+//! struct Body {
+//!     page_size: u32,
+//! }
+//!
+//! let json = serde_json::to_string(&Request { body: Body { page_size: 10 } }).unwrap();
+//! assert_eq!(json, r#"{"body":{"pageSize":10}}"#);
+//! ```
+//!
+//! Struct field names are converted once per style and kept for the
+//! lifetime of the program, as serde requires them to be static.
+//! Map keys are converted only on serialization, as the original style
+//! of keys is not known when deserializing. Enum variant names are not
+//! converted.
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use ::serde::{de, ser, Deserialize, Serialize};
+
+use crate::Case;
+
+/// Style is implemented by types that select the case for renaming on
+/// the type level, such as [`Camel`] or [`Snake`].
+pub trait Style {
+    /// Case into which names are converted.
+    const CASE: Case;
+}
+
+macro_rules! style {
+    ($name:ident, $module:ident, $example:literal) => {
+        #[doc = concat!("Renames into *", stringify!($name), "* case, for example `", $example, "`.")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl Style for $name {
+            const CASE: Case = Case::$name;
+        }
+
+        #[doc = concat!("Functions for the serde `with` field attribute that rename into *", stringify!($name), "* case.")]
+        pub mod $module {
+            /// Serializes the value with renamed field names and map keys.
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: ?Sized + ::serde::Serialize,
+                S: ::serde::Serializer,
+            {
+                super::serialize::<super::$name, T, S>(value, serializer)
+            }
+
+            /// Deserializes the value matching renamed field names.
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: ::serde::Deserialize<'de>,
+                D: ::serde::Deserializer<'de>,
+            {
+                super::deserialize::<super::$name, T, D>(deserializer)
+            }
+        }
+    };
+}
+
+style!(Camel, camel, "camelSnakeKebab");
+style!(Pascal, pascal, "CamelSnakeKebab");
+style!(Snake, snake, "camel_snake_kebab");
+style!(CamelSnake, camel_snake, "Camel_Snake_Kebab");
+style!(ScreamingSnake, screaming_snake, "CAMEL_SNAKE_KEBAB");
+style!(Kebab, kebab, "camel-snake-kebab");
+style!(CamelKebab, camel_kebab, "Camel-Snake-Kebab");
+style!(ScreamingKebab, screaming_kebab, "CAMEL-SNAKE-KEBAB");
+style!(Lower, lower, "camel snake kebab");
+style!(Title, title, "Camel Snake Kebab");
+style!(Screaming, screaming, "CAMEL SNAKE KEBAB");
+
+/// Renamed wraps a value so that names of its fields and keys of its
+/// maps, including all nested ones, are written in the case selected
+/// by the style `C` when serialized, and read in that case when
+/// deserialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Renamed<C, T> {
+    value: T,
+    style: PhantomData<C>,
+}
+
+impl<C, T> Renamed<C, T> {
+    /// Wraps the value.
+    pub fn new(value: T) -> Self {
+        Renamed {
+            value,
+            style: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<C, T> From<T> for Renamed<C, T> {
+    fn from(value: T) -> Self {
+        Renamed::new(value)
+    }
+}
+
+impl<C, T> Deref for Renamed<C, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<C, T> DerefMut for Renamed<C, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<C: Style, T: Serialize> Serialize for Renamed<C, T> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize::<C, T, S>(&self.value, serializer)
+    }
+}
+
+impl<'de, C: Style, T: Deserialize<'de>> Deserialize<'de> for Renamed<C, T> {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize::<C, T, D>(deserializer).map(Renamed::new)
+    }
+}
+
+fn serialize<C, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    C: Style,
+    T: ?Sized + Serialize,
+    S: ser::Serializer,
+{
+    value.serialize(Serializer::<C, S>::new(serializer, false))
+}
+
+fn deserialize<'de, C, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    C: Style,
+    T: Deserialize<'de>,
+    D: de::Deserializer<'de>,
+{
+    T::deserialize(Deserializer::<C, D>::new(deserializer))
+}
+
+/// Returns the static name converted into the case. Every distinct
+/// conversion is allocated only once.
+fn rename(case: Case, name: &'static str) -> &'static str {
+    if case.matches(name) {
+        return name;
+    }
+    static NAMES: OnceLock<Mutex<HashMap<(Case, &'static str), &'static str>>> = OnceLock::new();
+    let mut names = NAMES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    names
+        .entry((case, name))
+        .or_insert_with(|| case.convert(name).leak())
+}
+
+/// Returns static field names converted into the case. Every distinct
+/// list of fields is allocated only once.
+fn rename_all(case: Case, names: &'static [&'static str]) -> &'static [&'static str] {
+    type Key = (Case, usize, usize);
+    static NAMES: OnceLock<Mutex<HashMap<Key, &'static [&'static str]>>> = OnceLock::new();
+    let mut cache = NAMES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    cache
+        .entry((case, names.as_ptr() as usize, names.len()))
+        .or_insert_with(|| {
+            names
+                .iter()
+                .map(|name| rename(case, name))
+                .collect::<Vec<_>>()
+                .leak()
+        })
+}
+
+/// Returns the original field name for the name converted into the
+/// case, or the name itself if no field matches. Original names that
+/// differ from the converted ones are not accepted, as they would
+/// otherwise be matched by the deserialized type.
+fn original<'a, E: de::Error>(
+    case: Case,
+    fields: &'static [&'static str],
+    name: &'a str,
+) -> Result<&'a str, E> {
+    match fields.iter().find(|field| rename(case, field) == name) {
+        Some(field) => Ok(field),
+        None if fields.contains(&name) => Err(E::unknown_field(name, rename_all(case, fields))),
+        None => Ok(name),
+    }
+}
+
+/// Serializer that renames struct fields and, when it serializes a map
+/// key, converts strings.
+struct Serializer<C, S> {
+    inner: S,
+    key: bool,
+    style: PhantomData<C>,
+}
+
+impl<C, S> Serializer<C, S> {
+    fn new(inner: S, key: bool) -> Self {
+        Serializer {
+            inner,
+            key,
+            style: PhantomData,
+        }
+    }
+}
+
+/// Serializable reference to a value that is serialized with renaming.
+struct Value<'a, C, T: ?Sized> {
+    value: &'a T,
+    key: bool,
+    style: PhantomData<C>,
+}
+
+impl<'a, C, T: ?Sized> Value<'a, C, T> {
+    fn new(value: &'a T, key: bool) -> Self {
+        Value {
+            value,
+            key,
+            style: PhantomData,
+        }
+    }
+}
+
+impl<C: Style, T: ?Sized + Serialize> Serialize for Value<'_, C, T> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value
+            .serialize(Serializer::<C, S>::new(serializer, self.key))
+    }
+}
+
+/// Compound serializer that wraps every element with renaming.
+struct Compound<C, S> {
+    inner: S,
+    style: PhantomData<C>,
+}
+
+impl<C, S> Compound<C, S> {
+    fn new(inner: S) -> Self {
+        Compound {
+            inner,
+            style: PhantomData,
+        }
+    }
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method(self, $($arg: $ty),*) -> Result<S::Ok, S::Error> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<C: Style, S: ser::Serializer> ser::Serializer for Serializer<C, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<C, S::SerializeSeq>;
+    type SerializeTuple = Compound<C, S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<C, S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<C, S::SerializeTupleVariant>;
+    type SerializeMap = Compound<C, S::SerializeMap>;
+    type SerializeStruct = Compound<C, S::SerializeStruct>;
+    type SerializeStructVariant = Compound<C, S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_i64(v: i64);
+        serialize_i128(v: i128);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_u64(v: u64);
+        serialize_u128(v: u128);
+        serialize_f32(v: f32);
+        serialize_f64(v: f64);
+        serialize_char(v: char);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(name: &'static str, variant_index: u32, variant: &'static str);
+    }
+
+    fn serialize_str(self, v: &str) -> Result<S::Ok, S::Error> {
+        if self.key {
+            self.inner.serialize_str(&C::CASE.convert(v))
+        } else {
+            self.inner.serialize_str(v)
+        }
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_some(&Value::<C, T>::new(value, self.key))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_newtype_struct(name, &Value::<C, T>::new(value, self.key))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_newtype_variant(
+            name,
+            variant_index,
+            variant,
+            &Value::<C, T>::new(value, false),
+        )
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        self.inner.serialize_seq(len).map(Compound::new)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        self.inner.serialize_tuple(len).map(Compound::new)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        self.inner
+            .serialize_tuple_struct(name, len)
+            .map(Compound::new)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        self.inner
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(Compound::new)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        self.inner.serialize_map(len).map(Compound::new)
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        self.inner.serialize_struct(name, len).map(Compound::new)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        self.inner
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(Compound::new)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! compound {
+    ($trait:ident, $method:ident) => {
+        impl<C: Style, S: ser::$trait> ser::$trait for Compound<C, S> {
+            type Ok = S::Ok;
+            type Error = S::Error;
+
+            fn $method<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), S::Error> {
+                self.inner.$method(&Value::<C, T>::new(value, false))
+            }
+
+            fn end(self) -> Result<S::Ok, S::Error> {
+                self.inner.end()
+            }
+        }
+    };
+}
+
+compound!(SerializeSeq, serialize_element);
+compound!(SerializeTuple, serialize_element);
+compound!(SerializeTupleStruct, serialize_field);
+compound!(SerializeTupleVariant, serialize_field);
+
+impl<C: Style, S: ser::SerializeMap> ser::SerializeMap for Compound<C, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), S::Error> {
+        self.inner.serialize_key(&Value::<C, T>::new(key, true))
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), S::Error> {
+        self.inner
+            .serialize_value(&Value::<C, T>::new(value, false))
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+macro_rules! compound_struct {
+    ($trait:ident) => {
+        impl<C: Style, S: ser::$trait> ser::$trait for Compound<C, S> {
+            type Ok = S::Ok;
+            type Error = S::Error;
+
+            fn serialize_field<T: ?Sized + Serialize>(
+                &mut self,
+                key: &'static str,
+                value: &T,
+            ) -> Result<(), S::Error> {
+                self.inner
+                    .serialize_field(rename(C::CASE, key), &Value::<C, T>::new(value, false))
+            }
+
+            fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+                self.inner.skip_field(rename(C::CASE, key))
+            }
+
+            fn end(self) -> Result<S::Ok, S::Error> {
+                self.inner.end()
+            }
+        }
+    };
+}
+
+compound_struct!(SerializeStruct);
+compound_struct!(SerializeStructVariant);
+
+/// Deserializer that matches renamed struct fields.
+struct Deserializer<C, D> {
+    inner: D,
+    style: PhantomData<C>,
+}
+
+impl<C, D> Deserializer<C, D> {
+    fn new(inner: D) -> Self {
+        Deserializer {
+            inner,
+            style: PhantomData,
+        }
+    }
+}
+
+/// Visitor that wraps all nested deserializers. If fields are set, map
+/// keys are mapped back to the original struct field names.
+struct Visitor<C, V> {
+    inner: V,
+    fields: Option<&'static [&'static str]>,
+    style: PhantomData<C>,
+}
+
+impl<C, V> Visitor<C, V> {
+    fn new(inner: V, fields: Option<&'static [&'static str]>) -> Self {
+        Visitor {
+            inner,
+            fields,
+            style: PhantomData,
+        }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                self.inner.$method($($arg,)* Visitor::<C, V>::new(visitor, None))
+            }
+        )*
+    };
+}
+
+impl<'de, C: Style, D: de::Deserializer<'de>> de::Deserializer<'de> for Deserializer<C, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.deserialize_struct(
+            name,
+            rename_all(C::CASE, fields),
+            Visitor::<C, V>::new(visitor, Some(fields)),
+        )
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<V::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, C: Style, V: de::Visitor<'de>> de::Visitor<'de> for Visitor<C, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_some<D: de::Deserializer<'de>>(self, d: D) -> Result<V::Value, D::Error> {
+        self.inner.visit_some(Deserializer::<C, D>::new(d))
+    }
+
+    fn visit_newtype_struct<D: de::Deserializer<'de>>(self, d: D) -> Result<V::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(Deserializer::<C, D>::new(d))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_seq(Access::<C, A>::new(seq, None))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_map(Access::<C, A>::new(map, self.fields))
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_enum(Access::<C, A>::new(data, None))
+    }
+}
+
+/// Access to sequences, maps, enums and their variants, that wraps all
+/// nested deserializers.
+struct Access<C, A> {
+    inner: A,
+    fields: Option<&'static [&'static str]>,
+    style: PhantomData<C>,
+}
+
+impl<C, A> Access<C, A> {
+    fn new(inner: A, fields: Option<&'static [&'static str]>) -> Self {
+        Access {
+            inner,
+            fields,
+            style: PhantomData,
+        }
+    }
+}
+
+/// Seed that deserializes the value with a wrapped deserializer. If
+/// fields are set, the value is a struct field name that is mapped back
+/// to the original name.
+struct Seed<C, S> {
+    inner: S,
+    fields: Option<&'static [&'static str]>,
+    style: PhantomData<C>,
+}
+
+impl<C, S> Seed<C, S> {
+    fn new(inner: S, fields: Option<&'static [&'static str]>) -> Self {
+        Seed {
+            inner,
+            fields,
+            style: PhantomData,
+        }
+    }
+}
+
+impl<'de, C: Style, S: de::DeserializeSeed<'de>> de::DeserializeSeed<'de> for Seed<C, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, d: D) -> Result<S::Value, D::Error> {
+        match self.fields {
+            Some(fields) => d.deserialize_identifier(Field::<C, S>::new(self.inner, fields)),
+            None => self.inner.deserialize(Deserializer::<C, D>::new(d)),
+        }
+    }
+}
+
+/// Visitor of struct field names that maps them back to the original
+/// names and passes them to the seed.
+struct Field<C, S> {
+    seed: S,
+    fields: &'static [&'static str],
+    style: PhantomData<C>,
+}
+
+impl<C, S> Field<C, S> {
+    fn new(seed: S, fields: &'static [&'static str]) -> Self {
+        Field {
+            seed,
+            fields,
+            style: PhantomData,
+        }
+    }
+}
+
+impl<'de, C: Style, S: de::DeserializeSeed<'de>> de::Visitor<'de> for Field<C, S> {
+    type Value = S::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("field identifier")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<S::Value, E> {
+        let name = original(C::CASE, self.fields, v)?;
+        self.seed
+            .deserialize(de::value::StrDeserializer::<E>::new(name))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<S::Value, E> {
+        self.seed
+            .deserialize(de::value::U64Deserializer::<E>::new(v))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<S::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.visit_str(v),
+            Err(_) => self
+                .seed
+                .deserialize(de::value::BytesDeserializer::<E>::new(v)),
+        }
+    }
+}
+
+impl<'de, C: Style, A: de::SeqAccess<'de>> de::SeqAccess<'de> for Access<C, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        self.inner.next_element_seed(Seed::<C, T>::new(seed, None))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, C: Style, A: de::MapAccess<'de>> de::MapAccess<'de> for Access<C, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        self.inner
+            .next_key_seed(Seed::<C, K>::new(seed, self.fields))
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, A::Error> {
+        self.inner.next_value_seed(Seed::<C, V>::new(seed, None))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, C: Style, A: de::EnumAccess<'de>> de::EnumAccess<'de> for Access<C, A> {
+    type Error = A::Error;
+    type Variant = Access<C, A::Variant>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), A::Error> {
+        self.inner
+            .variant_seed(seed)
+            .map(|(value, variant)| (value, Access::new(variant, None)))
+    }
+}
+
+impl<'de, C: Style, A: de::VariantAccess<'de>> de::VariantAccess<'de> for Access<C, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, A::Error> {
+        self.inner
+            .newtype_variant_seed(Seed::<C, T>::new(seed, None))
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        self.inner
+            .tuple_variant(len, Visitor::<C, V>::new(visitor, None))
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        self.inner.struct_variant(
+            rename_all(C::CASE, fields),
+            Visitor::<C, V>::new(visitor, Some(fields)),
+        )
+    }
+}
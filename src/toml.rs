@@ -0,0 +1,200 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion of keys in TOML documents that preserves comments,
+//! ordering and formatting of the document.
+
+use std::{error, fmt};
+
+use toml_edit::{DocumentMut, InlineTable, Item, Key, Table, TomlError, Value};
+
+pub use crate::keys::KeyCollision;
+use crate::{
+    keys::{check_collisions, Exclusions},
+    Case,
+};
+
+/// Recursively converts keys of all tables in the document into the
+/// case. Depth limit and exclusions have the same meaning as in
+/// [`json::convert_keys`](crate::json::convert_keys), where elements of
+/// arrays of tables are addressed by their index.
+///
+/// If any two keys of the same table would be converted into the same
+/// key, the error is returned and the document is not changed.
+pub fn convert_keys(
+    document: &mut DocumentMut,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &[&str],
+) -> Result<(), KeyCollision> {
+    let exclusions = Exclusions::new(exclusions);
+    let mut path = Vec::new();
+    let table = document.as_table_mut();
+    walk_table(table, case, depth_limit, &exclusions, &mut path, false)?;
+    walk_table(table, case, depth_limit, &exclusions, &mut path, true)
+}
+
+/// Parses the TOML document, converts its keys with [`convert_keys`] and
+/// returns the formatted document.
+pub fn convert_str(
+    s: &str,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &[&str],
+) -> Result<String, Error> {
+    let mut document: DocumentMut = s.parse().map_err(Error::Parse)?;
+    convert_keys(&mut document, case, depth_limit, exclusions).map_err(Error::KeyCollision)?;
+    Ok(document.to_string())
+}
+
+/// Error returned by [`convert_str`].
+#[derive(Debug)]
+pub enum Error {
+    /// The document is not valid TOML.
+    Parse(TomlError),
+    /// Two keys are converted into the same key.
+    KeyCollision(KeyCollision),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "parse toml: {e}"),
+            Error::KeyCollision(e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(e) => Some(e),
+            Error::KeyCollision(e) => Some(e),
+        }
+    }
+}
+
+fn walk_table(
+    table: &mut Table,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &Exclusions,
+    path: &mut Vec<String>,
+    apply: bool,
+) -> Result<(), KeyCollision> {
+    if depth_limit == Some(0) || exclusions.matches(path) {
+        return Ok(());
+    }
+    let depth_limit = depth_limit.map(|d| d - 1);
+    if !apply {
+        check_collisions(case, table.iter().map(|(key, _)| key), path)?;
+        for (key, item) in table.iter_mut() {
+            path.push(key.get().to_string());
+            walk_item(item, case, depth_limit, exclusions, path, apply)?;
+            path.pop();
+        }
+        return Ok(());
+    }
+    let keys: Vec<String> = table.iter().map(|(key, _)| key.to_string()).collect();
+    for key in keys {
+        let Some((key, mut item)) = table.remove_entry(&key) else {
+            continue;
+        };
+        path.push(key.get().to_string());
+        walk_item(&mut item, case, depth_limit, exclusions, path, apply)?;
+        path.pop();
+        table.insert_formatted(&rename(case, &key), item);
+    }
+    Ok(())
+}
+
+fn walk_inline_table(
+    table: &mut InlineTable,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &Exclusions,
+    path: &mut Vec<String>,
+    apply: bool,
+) -> Result<(), KeyCollision> {
+    if depth_limit == Some(0) || exclusions.matches(path) {
+        return Ok(());
+    }
+    let depth_limit = depth_limit.map(|d| d - 1);
+    if !apply {
+        check_collisions(case, table.iter().map(|(key, _)| key), path)?;
+        for (key, value) in table.iter_mut() {
+            path.push(key.get().to_string());
+            walk_value(value, case, depth_limit, exclusions, path, apply)?;
+            path.pop();
+        }
+        return Ok(());
+    }
+    let keys: Vec<String> = table.iter().map(|(key, _)| key.to_string()).collect();
+    for key in keys {
+        let Some((key, mut value)) = table.remove_entry(&key) else {
+            continue;
+        };
+        path.push(key.get().to_string());
+        walk_value(&mut value, case, depth_limit, exclusions, path, apply)?;
+        path.pop();
+        table.insert_formatted(&rename(case, &key), value);
+    }
+    Ok(())
+}
+
+fn walk_item(
+    item: &mut Item,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &Exclusions,
+    path: &mut Vec<String>,
+    apply: bool,
+) -> Result<(), KeyCollision> {
+    match item {
+        Item::Table(table) => walk_table(table, case, depth_limit, exclusions, path, apply),
+        Item::ArrayOfTables(tables) => {
+            for (i, table) in tables.iter_mut().enumerate() {
+                path.push(i.to_string());
+                walk_table(table, case, depth_limit, exclusions, path, apply)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        Item::Value(value) => walk_value(value, case, depth_limit, exclusions, path, apply),
+        Item::None => Ok(()),
+    }
+}
+
+fn walk_value(
+    value: &mut Value,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &Exclusions,
+    path: &mut Vec<String>,
+    apply: bool,
+) -> Result<(), KeyCollision> {
+    match value {
+        Value::InlineTable(table) => {
+            walk_inline_table(table, case, depth_limit, exclusions, path, apply)
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter_mut().enumerate() {
+                path.push(i.to_string());
+                walk_value(value, case, depth_limit, exclusions, path, apply)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the converted key with comments and whitespace around the
+/// original key.
+fn rename(case: Case, key: &Key) -> Key {
+    Key::new(case.convert(key.get()))
+        .with_leaf_decor(key.leaf_decor().clone())
+        .with_dotted_decor(key.dotted_decor().clone())
+}
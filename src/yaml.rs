@@ -0,0 +1,154 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Conversion of keys in YAML documents.
+//!
+//! Ordering of keys is preserved, but comments are not, as the YAML
+//! parser does not retain them.
+
+use std::{error, fmt};
+
+use serde::Deserialize;
+use serde_yaml::{Mapping, Value};
+
+pub use crate::keys::KeyCollision;
+use crate::{
+    keys::{check_collisions, Exclusions},
+    Case,
+};
+
+/// Recursively converts string keys of all mappings in the value into
+/// the case. Depth limit and exclusions have the same meaning as in
+/// [`json::convert_keys`](crate::json::convert_keys). Keys that are not
+/// strings are not converted.
+///
+/// If any two keys of the same mapping would be converted into the same
+/// key, the error is returned and the value is not changed.
+pub fn convert_keys(
+    value: &mut Value,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &[&str],
+) -> Result<(), KeyCollision> {
+    let exclusions = Exclusions::new(exclusions);
+    let mut path = Vec::new();
+    walk(value, case, depth_limit, &exclusions, &mut path, false)?;
+    walk(value, case, depth_limit, &exclusions, &mut path, true)
+}
+
+/// Parses the YAML stream, converts keys of every document in it with
+/// [`convert_keys`] and returns the formatted documents separated by
+/// `---` lines.
+pub fn convert_str(
+    s: &str,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &[&str],
+) -> Result<String, Error> {
+    let mut documents = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(s) {
+        let mut value = Value::deserialize(document).map_err(Error::Parse)?;
+        convert_keys(&mut value, case, depth_limit, exclusions).map_err(Error::KeyCollision)?;
+        documents.push(serde_yaml::to_string(&value).map_err(Error::Format)?);
+    }
+    Ok(documents.join("---\n"))
+}
+
+/// Error returned by [`convert_str`].
+#[derive(Debug)]
+pub enum Error {
+    /// The document is not valid YAML.
+    Parse(serde_yaml::Error),
+    /// The converted document can not be formatted as YAML.
+    Format(serde_yaml::Error),
+    /// Two keys are converted into the same key.
+    KeyCollision(KeyCollision),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "parse yaml: {e}"),
+            Error::Format(e) => write!(f, "format yaml: {e}"),
+            Error::KeyCollision(e) => e.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(e) | Error::Format(e) => Some(e),
+            Error::KeyCollision(e) => Some(e),
+        }
+    }
+}
+
+fn walk(
+    value: &mut Value,
+    case: Case,
+    depth_limit: Option<usize>,
+    exclusions: &Exclusions,
+    path: &mut Vec<String>,
+    apply: bool,
+) -> Result<(), KeyCollision> {
+    match value {
+        Value::Mapping(mapping) => {
+            if depth_limit == Some(0) || exclusions.matches(path) {
+                return Ok(());
+            }
+            let depth_limit = depth_limit.map(|d| d - 1);
+            if !apply {
+                check_collisions(case, mapping.keys().filter_map(Value::as_str), path)?;
+                for (key, value) in mapping.iter_mut() {
+                    path.push(segment(key));
+                    walk(value, case, depth_limit, exclusions, path, apply)?;
+                    path.pop();
+                }
+                return Ok(());
+            }
+            let mut converted = Mapping::with_capacity(mapping.len());
+            for (key, mut value) in std::mem::take(mapping) {
+                path.push(segment(&key));
+                walk(&mut value, case, depth_limit, exclusions, path, apply)?;
+                path.pop();
+                let key = match key {
+                    Value::String(key) => Value::String(case.convert(&key)),
+                    key => key,
+                };
+                converted.insert(key, value);
+            }
+            *mapping = converted;
+            Ok(())
+        }
+        Value::Sequence(values) => {
+            for (i, value) in values.iter_mut().enumerate() {
+                path.push(i.to_string());
+                walk(value, case, depth_limit, exclusions, path, apply)?;
+                path.pop();
+            }
+            Ok(())
+        }
+        Value::Tagged(tagged) => walk(
+            &mut tagged.value,
+            case,
+            depth_limit,
+            exclusions,
+            path,
+            apply,
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the path segment of the mapping key.
+fn segment(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        _ => String::new(),
+    }
+}
@@ -0,0 +1,624 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+#![cfg(feature = "build-binary")]
+
+use assert_cmd::Command;
+
+fn casbab() -> Command {
+    Command::cargo_bin("casbab").unwrap()
+}
+
+#[test]
+fn csv_headers_test() {
+    casbab()
+        .args(["csv-headers", "snake"])
+        .write_stdin("Customer ID,Order-Date,\"Ship To\"\r\n\"a,b\",Order-Date,x\r\n1,2,3")
+        .assert()
+        .success()
+        .stdout("customer_id,order_date,\"ship_to\"\r\n\"a,b\",Order-Date,x\r\n1,2,3")
+        .stderr("");
+}
+
+#[test]
+fn csv_headers_quoting_test() {
+    casbab()
+        .args([
+            "csv-headers",
+            "camel-kebab",
+            "--delimiter",
+            ";",
+            "--quote",
+            "'",
+        ])
+        .write_stdin("'multi\nline';first name;'it''s'\n1;2;3\n")
+        .assert()
+        .success()
+        .stdout("'Multi\nline';First-Name;'It''s'\n1;2;3\n");
+}
+
+#[test]
+fn csv_headers_duplicates_test() {
+    casbab()
+        .args(["csv-headers", "camel"])
+        .write_stdin("\u{feff}Customer ID,customer_id,Total\n")
+        .assert()
+        .success()
+        .stdout("\u{feff}customerId,customerId,total\n")
+        .stderr("warning: columns 1 and 2 are both converted to `customerId`\n");
+}
+
+#[test]
+fn csv_headers_errors_test() {
+    casbab()
+        .args(["csv-headers", "snake"])
+        .write_stdin("\"open,close\n")
+        .assert()
+        .failure()
+        .stderr("error: header row has an unterminated quoted field\n");
+}
+
+#[test]
+fn env_test() {
+    casbab()
+        .args([
+            "env",
+            "--prefix",
+            "app",
+            "database.pool.maxSize",
+            "http-server.URL",
+        ])
+        .assert()
+        .success()
+        .stdout("APP_DATABASE__POOL__MAX_SIZE\nAPP_HTTP_SERVER__URL\n");
+
+    casbab()
+        .args(["env", "--prefix", "app", "--inverse", "camel"])
+        .write_stdin("APP_DATABASE__POOL__MAX_SIZE\n")
+        .assert()
+        .success()
+        .stdout("database.pool.maxSize\n");
+
+    casbab()
+        .args(["env", "--prefix", "app", "--inverse", "camel", "OTHER_NAME"])
+        .assert()
+        .failure()
+        .stderr("error: `OTHER_NAME` does not start with the prefix\n");
+}
+
+#[test]
+fn all_test() {
+    casbab()
+        .args(["all", "it", "is", "HTTP"])
+        .assert()
+        .success()
+        .stdout(
+            "camel            itIsHttp
+pascal           ItIsHttp
+snake            it_is_http
+camel-snake      It_Is_Http
+screaming-snake  IT_IS_HTTP
+kebab            it-is-http
+camel-kebab      It-Is-Http
+screaming-kebab  IT-IS-HTTP
+lower            it is http
+title            It Is Http
+screaming        IT IS HTTP
+",
+        );
+
+    casbab()
+        .args(["all", "--format", "tsv", "fooBar"])
+        .assert()
+        .success()
+        .stdout(predicates::str::starts_with(
+            "camel\tfooBar\npascal\tFooBar\nsnake\tfoo_bar\n",
+        ));
+
+    let output = casbab()
+        .args(["all", "--format", "json", "say \"hi\""])
+        .output()
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["camel-snake"], "Say_\"hi\"");
+    assert_eq!(value.as_object().unwrap().len(), casbab::Case::ALL.len());
+}
+
+#[test]
+fn detect_test() {
+    casbab()
+        .args(["detect", "max_size", "server", "", "HTTP_server"])
+        .assert()
+        .success()
+        .stdout(
+            "max_size\tsnake\nserver\tambiguous: camel, snake, kebab, lower\nHTTP_server\tmixed\n",
+        );
+
+    casbab()
+        .args(["detect", "--expect", "snake"])
+        .write_stdin("max_size\nserver\nmaxSize\n")
+        .assert()
+        .failure()
+        .stdout("max_size\tsnake\nserver\tambiguous: camel, snake, kebab, lower\nmaxSize\tcamel\n")
+        .stderr("line 3: `maxSize` is not snake\nerror: phrases not written in snake: 1\n");
+
+    casbab()
+        .args(["detect", "--expect", "snake", "-0"])
+        .write_stdin("max_size\0maxSize\0")
+        .assert()
+        .failure()
+        .stderr("record 2: `maxSize` is not snake\nerror: phrases not written in snake: 1\n");
+
+    casbab()
+        .args(["detect", "--expect", "title", "Http Server"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn rewrite_test() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("user.py");
+    let file = path.to_str().unwrap();
+    let text = "def getUserName(self):\n    return self.user_name or DEFAULT_NAME\n";
+    std::fs::write(&path, text).unwrap();
+
+    casbab()
+        .args(["rewrite", "--from", "camel", "--to", "snake", "--dry-run", file])
+        .assert()
+        .success()
+        .stdout(format!(
+            "--- {file}\n+++ {file}\n@@ -1,2 +1,2 @@\n-def getUserName(self):\n+def get_user_name(self):\n     return self.user_name or DEFAULT_NAME\n"
+        ))
+        .stderr(format!(
+            "{file}: 1 identifier\n1 identifier in 1 file to rewrite\n"
+        ));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+
+    casbab()
+        .args(["rewrite", "--to", "camel", file])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr(format!(
+            "{file}: 2 identifiers\n2 identifiers in 1 file rewritten\n"
+        ));
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "def getUserName(self):\n    return self.userName or defaultName\n"
+    );
+}
+
+#[test]
+fn rewrite_keywords_test() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("value.py");
+    std::fs::write(&path, "def get_value(self): return self.my_value\n").unwrap();
+
+    casbab()
+        .args(["rewrite", "--from", "snake", "--to", "screaming-snake"])
+        .arg(&path)
+        .assert()
+        .success();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "def GET_VALUE(self): return self.MY_VALUE\n"
+    );
+}
+
+#[test]
+fn rewrite_pattern_test() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("index.md");
+    std::fs::write(&path, "[home](#getting-started) and {{ user-name }}\n").unwrap();
+
+    casbab()
+        .args(["rewrite", "--from", "kebab", "--to", "snake"])
+        .args(["--pattern", r"\{\{ ([\w-]+) \}\}"])
+        .arg(&path)
+        .assert()
+        .success();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "[home](#getting-started) and {{ user_name }}\n"
+    );
+
+    casbab()
+        .args(["rewrite", "--from", "kebab", "--to", "snake"])
+        .arg(&path)
+        .assert()
+        .success();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        "[home](#getting_started) and {{ user_name }}\n"
+    );
+
+    casbab()
+        .args(["rewrite", "--to", "snake", "--pattern", "("])
+        .arg(&path)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn rename_test() {
+    let dir = tempfile::tempdir().unwrap();
+    let photos = dir.path().join("My Photos");
+    std::fs::create_dir(&photos).unwrap();
+    std::fs::write(photos.join("Summer Trip.JPG"), "").unwrap();
+    std::fs::write(photos.join(".hidden"), "").unwrap();
+
+    casbab()
+        .args(["rename", "kebab", "--recursive", "--dry-run"])
+        .arg(&photos)
+        .assert()
+        .success()
+        .stdout(format!(
+            "{} -> {}\n{} -> {}\n",
+            photos.join("Summer Trip.JPG").display(),
+            photos.join("summer-trip.JPG").display(),
+            photos.display(),
+            dir.path().join("my-photos").display(),
+        ));
+    assert!(photos.join("Summer Trip.JPG").exists());
+
+    casbab()
+        .args(["rename", "kebab", "--recursive"])
+        .arg(&photos)
+        .assert()
+        .success();
+    let photos = dir.path().join("my-photos");
+    assert!(photos.join("summer-trip.JPG").exists());
+    assert!(photos.join(".hidden").exists());
+}
+
+#[test]
+fn rename_case_only_test() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("Foo-Bar.txt"), "foo").unwrap();
+
+    casbab()
+        .args(["rename", "kebab"])
+        .arg(dir.path().join("Foo-Bar.txt"))
+        .assert()
+        .success();
+    let names: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(names, ["foo-bar.txt"]);
+}
+
+#[test]
+fn rename_collisions_test() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in [
+        "foo_bar.txt",
+        "FooBar.txt",
+        "Baz.txt",
+        "baz-qux.txt",
+        "BazQux.txt",
+    ] {
+        std::fs::write(dir.path().join(name), "").unwrap();
+    }
+
+    casbab()
+        .args(["rename", "kebab"])
+        .arg(dir.path().join("foo_bar.txt"))
+        .arg(dir.path().join("FooBar.txt"))
+        .arg(dir.path().join("Baz.txt"))
+        .arg(dir.path().join("BazQux.txt"))
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(format!(
+            "error: {} and {} are both renamed to {}\nerror: {} is renamed to existing {}\nerror: 2 collisions, nothing is renamed\n",
+            dir.path().join("foo_bar.txt").display(),
+            dir.path().join("FooBar.txt").display(),
+            dir.path().join("foo-bar.txt").display(),
+            dir.path().join("BazQux.txt").display(),
+            dir.path().join("baz-qux.txt").display(),
+        ));
+    assert!(dir.path().join("Baz.txt").exists());
+
+    std::fs::write(dir.path().join("FooBar.TXT"), "").unwrap();
+    casbab()
+        .args(["rename", "kebab"])
+        .arg(dir.path().join("foo_bar.txt"))
+        .arg(dir.path().join("Baz.txt"))
+        .arg(dir.path().join("FooBar.TXT"))
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(format!(
+            "error: {} and {} are renamed to {} and {}, which differ only in letter case\nerror: 1 collisions, nothing is renamed\n",
+            dir.path().join("foo_bar.txt").display(),
+            dir.path().join("FooBar.TXT").display(),
+            dir.path().join("foo-bar.txt").display(),
+            dir.path().join("foo-bar.TXT").display(),
+        ));
+}
+
+#[test]
+fn stdin_test() {
+    casbab()
+        .arg("snake")
+        .write_stdin("fooBar\r\nBazQux\n\nlast")
+        .assert()
+        .success()
+        .stdout("foo_bar\nbaz_qux\n\nlast\n");
+}
+
+#[test]
+fn stdin_non_utf8_test() {
+    casbab()
+        .arg("snake")
+        .write_stdin(&b"fooBar\nba\xffd\nBazQux\n"[..])
+        .assert()
+        .failure()
+        .stdout("foo_bar\n")
+        .stderr("error: line 2: invalid UTF-8\n");
+
+    casbab()
+        .args(["snake", "-0"])
+        .write_stdin(&b"fooBar\0ba\xffd\0"[..])
+        .assert()
+        .failure()
+        .stdout("foo_bar\0")
+        .stderr("error: record 2: invalid UTF-8\n");
+
+    casbab()
+        .args(["snake", "--delimiter", ";"])
+        .write_stdin(&b"fooBar;ba\xffd;"[..])
+        .assert()
+        .failure()
+        .stdout("foo_bar;")
+        .stderr("error: record 2: invalid UTF-8\n");
+
+    casbab()
+        .args(["snake", "--non-utf8", "pass"])
+        .write_stdin(&b"fooBar\nba\xffd\nBazQux\n"[..])
+        .assert()
+        .success()
+        .stdout(&b"foo_bar\nba\xffd\nbaz_qux\n"[..]);
+}
+
+/// Runs the command with Stdout that is a pipe closed for reading before the
+/// command starts, writing the input to Stdin until it is closed too.
+#[cfg(unix)]
+fn closed_stdout(args: &[&str], input: &[u8]) -> std::process::Output {
+    use std::{
+        io::Write,
+        process::{Command, Stdio},
+    };
+
+    let (reader, writer) = std::io::pipe().unwrap();
+    drop(reader);
+    let mut child = Command::new(assert_cmd::cargo::cargo_bin("casbab"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(writer)
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let _ = stdin.write_all(input);
+    drop(stdin);
+    child.wait_with_output().unwrap()
+}
+
+#[cfg(unix)]
+#[test]
+fn stdout_broken_pipe_test() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("fooBar.txt");
+    std::fs::write(&file, "").unwrap();
+    let file = file.to_str().unwrap();
+    let phrases = "fooBar\n".repeat(100_000);
+    let document = format!("[{}{{}}]", "{\"fooBar\": 1},".repeat(1000));
+
+    for (args, input) in [
+        (&["kebab"][..], phrases.as_bytes()),
+        (&["kebab", "--keys", "json"], document.as_bytes()),
+        (&["all", "fooBar"], b""),
+        (&["rename", "snake", "--dry-run", file], b""),
+    ] {
+        let output = closed_stdout(args, input);
+        assert_eq!(output.status.code(), Some(141), "{args:?}");
+        assert_eq!(output.stderr, b"", "{args:?}");
+    }
+}
+
+#[test]
+fn delimiter_test() {
+    casbab()
+        .args(["snake", "-0"])
+        .write_stdin("My Photos/Summer\nTrip\0ReadMe\0")
+        .assert()
+        .success()
+        .stdout("my_photos/_summer\n_trip\0read_me\0");
+
+    casbab()
+        .args(["kebab", "--delimiter", "::", "fooBar", "BazQux"])
+        .assert()
+        .success()
+        .stdout("foo-bar::baz-qux::");
+
+    casbab()
+        .args(["env", "--null", "--prefix", "app"])
+        .write_stdin("db.maxSize\0db.host")
+        .assert()
+        .success()
+        .stdout("APP_DB__MAX_SIZE\0APP_DB__HOST\0");
+
+    casbab()
+        .args(["snake", "-0", "--delimiter", ";", "fooBar"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn match_test() {
+    let text = "# Config\r\n\nSet `maxSize` and `poolName` in {{ user_name }}.\nKeep it-simple";
+
+    casbab()
+        .args(["snake", "--only-style", "camel"])
+        .write_stdin(text)
+        .assert()
+        .success()
+        .stdout("# Config\r\n\nSet `max_size` and `pool_name` in {{ user_name }}.\nKeep it-simple");
+
+    casbab()
+        .args(["camel", "--match", r"\{\{ (\w+) \}\}"])
+        .write_stdin(text)
+        .assert()
+        .success()
+        .stdout("# Config\r\n\nSet `maxSize` and `poolName` in {{ userName }}.\nKeep it-simple");
+
+    casbab()
+        .args(["pascal", "--only-style", "camel"])
+        .write_stdin("the quick fooBar jumps over the lazy_dog\n")
+        .assert()
+        .success()
+        .stdout("the quick FooBar jumps over the lazy_dog\n");
+
+    casbab()
+        .args(["kebab", "--match", "("])
+        .write_stdin(text)
+        .assert()
+        .failure();
+
+    casbab()
+        .args(["snake", "--only-style", "camel"])
+        .write_stdin(&b"fooBar\nba\xffdBaz\nquxQuux"[..])
+        .assert()
+        .failure()
+        .stdout("foo_bar\n")
+        .stderr("error: stdin: line 2: invalid UTF-8\n");
+
+    casbab()
+        .args(["snake", "--only-style", "camel", "--non-utf8", "pass"])
+        .write_stdin(&b"fooBar\nba\xffdBaz\nquxQuux"[..])
+        .assert()
+        .success()
+        .stdout(&b"foo_bar\nba\xffdBaz\nqux_quux"[..]);
+}
+
+#[test]
+fn keys_test() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("values.yaml");
+    std::fs::write(&file, "api_version: v1\n---\nreplica_count: 2\n").unwrap();
+
+    casbab()
+        .args(["camel", "--keys", "yaml"])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout("apiVersion: v1\n---\nreplicaCount: 2\n");
+
+    casbab()
+        .args(["camel", "--keys", "json"])
+        .write_stdin(&b"{\"foo_bar\": \"\xff\"}"[..])
+        .assert()
+        .failure()
+        .stderr("error: stdin: invalid UTF-8\n");
+}
+
+/// Compares the output of the command with the file in the `snapshots`
+/// directory, or overwrites the file if the `CASBAB_UPDATE_SNAPSHOTS`
+/// environment variable is set.
+fn assert_snapshot(args: &[&str], name: &str) {
+    let output = casbab().args(args).output().unwrap();
+    assert!(output.status.success(), "{args:?}");
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(name);
+    if std::env::var_os("CASBAB_UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, &output.stdout).unwrap();
+        return;
+    }
+    let snapshot = std::fs::read(&path).unwrap();
+    assert!(
+        output.stdout == snapshot,
+        "output of {args:?} differs from {}, run tests with CASBAB_UPDATE_SNAPSHOTS=1 to update it",
+        path.display()
+    );
+}
+
+#[test]
+fn completions_test() {
+    for (shell, name) in [
+        ("bash", "casbab.bash"),
+        ("elvish", "casbab.elv"),
+        ("fish", "casbab.fish"),
+        ("powershell", "_casbab.ps1"),
+        ("zsh", "_casbab"),
+    ] {
+        assert_snapshot(&["completions", shell], name);
+    }
+}
+
+#[test]
+fn man_test() {
+    assert_snapshot(&["man"], "casbab.1");
+}
+
+#[test]
+fn dialect_aliases_test() {
+    for (dialect, converted) in [
+        ("title", "Foo Bar Baz"),
+        ("TITLE", "Foo Bar Baz"),
+        ("kebab-case", "foo-bar-baz"),
+        ("SCREAMING_SNAKE", "FOO_BAR_BAZ"),
+        ("screaming_snake", "FOO_BAR_BAZ"),
+        ("constant", "FOO_BAR_BAZ"),
+        ("upper", "FOO BAR BAZ"),
+        ("camelCase", "fooBarBaz"),
+        ("train", "Foo-Bar-Baz"),
+    ] {
+        casbab()
+            .args([dialect, "foo bar baz"])
+            .assert()
+            .success()
+            .stdout(format!("{converted}\n"));
+    }
+
+    casbab()
+        .args([
+            "env",
+            "--prefix",
+            "app",
+            "--inverse",
+            "camelCase",
+            "APP_FOO__BAR_BAZ",
+        ])
+        .assert()
+        .success()
+        .stdout("foo.barBaz\n");
+}
+
+#[test]
+fn dialect_suggestions_test() {
+    casbab()
+        .args(["kebap", "foo bar"])
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicates::str::contains(
+            "error: invalid value 'kebap' for '<dialect>'",
+        ))
+        .stderr(predicates::str::contains(
+            "tip: a similar value exists: 'kebab'",
+        ));
+
+    casbab()
+        .args(["detect", "--expect", "snak", "foo_bar"])
+        .assert()
+        .code(2)
+        .stderr(predicates::str::contains(
+            "tip: a similar value exists: 'snake'",
+        ));
+}
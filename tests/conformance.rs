@@ -0,0 +1,101 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Runs the conformance corpus that is shared with the Go implementation
+//! and reports all divergences at once. The corpus is read from
+//! `conformance/cases.json`, or from the file set in the
+//! `CASBAB_CONFORMANCE` environment variable.
+
+use casbab::Case;
+use serde::Deserialize;
+use std::{collections::BTreeMap, env, fs, path::PathBuf};
+
+#[derive(Deserialize)]
+struct Corpus {
+    cases: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    inputs: Vec<String>,
+    expected: BTreeMap<String, String>,
+}
+
+const STYLES: [(&str, Case); 11] = [
+    ("camel", Case::Camel),
+    ("pascal", Case::Pascal),
+    ("snake", Case::Snake),
+    ("camel_snake", Case::CamelSnake),
+    ("screaming_snake", Case::ScreamingSnake),
+    ("kebab", Case::Kebab),
+    ("camel_kebab", Case::CamelKebab),
+    ("screaming_kebab", Case::ScreamingKebab),
+    ("lower", Case::Lower),
+    ("title", Case::Title),
+    ("screaming", Case::Screaming),
+];
+
+#[test]
+fn conformance_test() {
+    check(|case, input| Some(case.convert(input)));
+}
+
+/// Checks that literals converted at compile time, which are limited to
+/// ASCII, are the same as at runtime.
+#[test]
+fn lit_conformance_test() {
+    check(|case, input| {
+        if !input.is_ascii() {
+            return None;
+        }
+        let (bytes, n) = casbab::lit::convert::<256>(case, input);
+        assert!(n <= bytes.len(), "{input:?} is too long");
+        Some(String::from_utf8(bytes[..n].to_vec()).unwrap())
+    });
+}
+
+/// Converts all inputs of the corpus with the function, skipping those for
+/// which it returns `None`, and reports all divergences at once.
+fn check(convert: impl Fn(Case, &str) -> Option<String>) {
+    let path = env::var_os("CASBAB_CONFORMANCE").map_or_else(
+        || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("conformance/cases.json"),
+        PathBuf::from,
+    );
+    let data =
+        fs::read_to_string(&path).unwrap_or_else(|e| panic!("read corpus {}: {e}", path.display()));
+    let corpus: Corpus = serde_json::from_str(&data)
+        .unwrap_or_else(|e| panic!("parse corpus {}: {e}", path.display()));
+
+    let mut checks = 0;
+    let mut divergences = Vec::new();
+    for entry in &corpus.cases {
+        for (style, expected) in &entry.expected {
+            let Some((_, case)) = STYLES.iter().find(|(name, _)| name == style) else {
+                divergences.push(format!("unknown style `{style}`"));
+                continue;
+            };
+            for input in &entry.inputs {
+                let Some(got) = convert(*case, input) else {
+                    continue;
+                };
+                checks += 1;
+                if &got != expected {
+                    divergences.push(format!(
+                        "{style}({input:?}): expected {expected:?}, got {got:?}"
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(checks > 0, "corpus {} has no cases", path.display());
+    assert!(
+        divergences.is_empty(),
+        "{} divergences in {checks} conversions from {}:\n{}",
+        divergences.len(),
+        path.display(),
+        divergences.join("\n")
+    );
+}
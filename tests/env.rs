@@ -0,0 +1,72 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use casbab::{env::Env, Case};
+
+#[test]
+fn name_test() {
+    let cases = [
+        (
+            Env::new(),
+            "database.pool.maxSize",
+            "DATABASE__POOL__MAX_SIZE",
+        ),
+        (
+            Env::new().prefix("app"),
+            "database.pool.maxSize",
+            "APP_DATABASE__POOL__MAX_SIZE",
+        ),
+        (
+            Env::new().prefix("myApp"),
+            "http-server.URL",
+            "MY_APP_HTTP_SERVER__URL",
+        ),
+        (Env::new(), "a..b.", "A__B"),
+        (Env::new(), "__private.key_", "PRIVATE__KEY"),
+        (Env::new(), "cache.TTL (seconds)", "CACHE__TTL_SECONDS"),
+        (Env::new(), "straße.größe", "STRASSE__GR_SSE"),
+        (Env::new(), "1st.key", "_1ST__KEY"),
+        (Env::new().prefix("app"), "1st.key", "APP_1ST__KEY"),
+        (Env::new().prefix("app"), "", "APP"),
+        (
+            Env::new().separator("_0_").delimiters(&['/', '.']),
+            "server/http.port",
+            "SERVER_0_HTTP_0_PORT",
+        ),
+    ];
+    for (env, path, name) in cases {
+        assert_eq!(env.name(path), name, "{path}");
+    }
+}
+
+#[test]
+fn path_test() {
+    let env = Env::new().prefix("app");
+    assert_eq!(
+        env.path("APP_DATABASE__POOL__MAX_SIZE", Case::Camel)
+            .as_deref(),
+        Some("database.pool.maxSize")
+    );
+    assert_eq!(
+        env.path("APP_DATABASE__POOL__MAX_SIZE", Case::Kebab)
+            .as_deref(),
+        Some("database.pool.max-size")
+    );
+    assert_eq!(env.path("APP", Case::Snake).as_deref(), Some(""));
+    assert_eq!(env.path("APPLICATION_NAME", Case::Snake), None);
+    assert_eq!(env.path("OTHER_NAME", Case::Snake), None);
+
+    assert_eq!(
+        Env::new().path("_1__KEY", Case::Snake).as_deref(),
+        Some("1.key")
+    );
+    assert_eq!(
+        Env::new()
+            .delimiters(&['/'])
+            .path("SERVER__HTTP_PORT", Case::Snake)
+            .as_deref(),
+        Some("server/http_port")
+    );
+}
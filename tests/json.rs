@@ -0,0 +1,97 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+#![cfg(feature = "json")]
+
+use casbab::{json::convert_keys, Case};
+use serde_json::json;
+
+#[test]
+fn convert_keys_test() {
+    let mut value = json!({
+        "user_id": 1,
+        "home_url": "https://example.com",
+        "roles": [{"role_name": "admin", "granted_at": null}],
+        "metadata": {"free_form": {"any_key": true}},
+        "items": [
+            {"item_id": 1, "labels": {"app_name": "web"}},
+            {"item_id": 2, "labels": {"team_name": "core"}},
+        ],
+        "nested_object": {"deeper_object": {"deepest_key": 1}},
+    });
+
+    convert_keys(
+        &mut value,
+        Case::Camel,
+        None,
+        &["/metadata", "/items/*/labels"],
+    )
+    .unwrap();
+
+    assert_eq!(
+        value,
+        json!({
+            "userId": 1,
+            "homeUrl": "https://example.com",
+            "roles": [{"roleName": "admin", "grantedAt": null}],
+            "metadata": {"free_form": {"any_key": true}},
+            "items": [
+                {"itemId": 1, "labels": {"app_name": "web"}},
+                {"itemId": 2, "labels": {"team_name": "core"}},
+            ],
+            "nestedObject": {"deeperObject": {"deepestKey": 1}},
+        })
+    );
+}
+
+#[test]
+fn convert_keys_depth_limit_test() {
+    let mut value = json!([{"first_level": {"second_level": {"third_level": 1}}}]);
+
+    convert_keys(&mut value, Case::ScreamingSnake, Some(2), &[]).unwrap();
+    assert_eq!(
+        value,
+        json!([{"FIRST_LEVEL": {"SECOND_LEVEL": {"third_level": 1}}}])
+    );
+
+    convert_keys(&mut value, Case::Kebab, Some(0), &[]).unwrap();
+    assert_eq!(
+        value,
+        json!([{"FIRST_LEVEL": {"SECOND_LEVEL": {"third_level": 1}}}])
+    );
+}
+
+#[test]
+fn convert_keys_collision_test() {
+    let original = json!({
+        "user_id": 1,
+        "nested": {"fooBar": 1, "foo_bar": 2},
+    });
+    let mut value = original.clone();
+
+    let err = convert_keys(&mut value, Case::Camel, None, &[]).unwrap_err();
+    assert_eq!(err.path(), "/nested");
+    assert_eq!(err.keys(), ("fooBar", "foo_bar"));
+    assert_eq!(err.converted(), "fooBar");
+    assert_eq!(
+        err.to_string(),
+        "keys `fooBar` and `foo_bar` at `/nested` are both converted to `fooBar`"
+    );
+    assert_eq!(value, original);
+
+    convert_keys(&mut value, Case::Camel, None, &["/nested"]).unwrap();
+    assert_eq!(
+        value,
+        json!({"userId": 1, "nested": {"fooBar": 1, "foo_bar": 2}})
+    );
+}
+
+#[test]
+fn convert_keys_escaped_exclusion_test() {
+    let mut value = json!({"a/b": {"c_d": 1}, "e~f": {"g_h": 2}});
+
+    convert_keys(&mut value, Case::Kebab, None, &["/a~1b", "e~0f"]).unwrap();
+    assert_eq!(value, json!({"a/b": {"c_d": 1}, "e~f": {"g_h": 2}}));
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! This crate is not published. It only uses the casbab library from a
+//! `no_std` crate to verify that the library builds with `alloc` only.
+//!
+//! Build it on its own, so that features are not unified with other
+//! workspace members that enable the `std` feature:
+//!
+//! ```sh
+//! cargo build -p casbab-no-std
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+/// Converts the configuration key with an allocation.
+pub fn config_key(s: &str) -> String {
+    casbab::snake(s)
+}
+
+/// Converts configuration keys with a single allocation.
+pub fn config_keys(keys: &[&str], buf: &mut String) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    casbab::convert_many_into(casbab::Case::Snake, keys, buf, &mut offsets);
+    offsets
+}
+
+/// Writes the configuration key into a fixed size buffer without any
+/// allocation for ASCII keys.
+pub fn write_config_key<'a>(s: &str, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+    let mut w = Buffer { buf, len: 0 };
+    write!(w, "{}", casbab::Snake(s))?;
+    let Buffer { buf, len } = w;
+    core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
+
+struct Buffer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for Buffer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
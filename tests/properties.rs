@@ -0,0 +1,162 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+//! Properties of conversions checked with generated phrases. Minimal
+//! failing phrases found by proptest are stored in the
+//! `properties.proptest-regressions` file next to this one and they are
+//! checked first on every run, so the file should be committed.
+//!
+//! Characters without letter case, such as digits, are treated as lower
+//! case letters when words are split, in the same way as in the Go
+//! implementation, so phrases that contain them do not have all of these
+//! properties. For example `0a` is converted to `0A` in *Screaming kebab*
+//! case, which is then split into two words. Phrases of words contain only
+//! letters and separators, and known counterexamples are kept in the
+//! `counterexamples` test. Letters without a case mapping, such as `ℋ`,
+//! are not generated for the same reason, and neither is `σ`, which is
+//! converted into `ς` at the end of words. Digits and other characters are
+//! generated only for properties that do not depend on word splitting.
+
+use casbab::{reversible, Case};
+use proptest::prelude::*;
+
+const FAMILIES: [&[Case]; 4] = [
+    &[Case::Camel, Case::Pascal],
+    &[Case::Snake, Case::CamelSnake, Case::ScreamingSnake],
+    &[Case::Kebab, Case::CamelKebab, Case::ScreamingKebab],
+    &[Case::Lower, Case::Title, Case::Screaming],
+];
+
+fn case() -> impl Strategy<Value = Case> {
+    proptest::sample::select(&Case::ALL[..])
+}
+
+/// Returns words that have at least two letters and are written in lower,
+/// upper or title case, so that they are split in the same way after any
+/// conversion. Single letter words are not generated as they are merged
+/// with neighbours in *Pascal* case, for example `a b` into `AB`.
+fn word() -> impl Strategy<Value = String> {
+    ("[a-zà-öø-ÿα-ρτ-ωа-џ]{2,8}", 0..3).prop_map(|(w, style)| match style {
+        0 => w,
+        1 => w.to_uppercase(),
+        _ => {
+            let mut chars = w.chars();
+            let first = chars.next().unwrap();
+            first.to_uppercase().chain(chars).collect()
+        }
+    })
+}
+
+/// Returns phrases of words separated by any combination of separators.
+fn phrase() -> impl Strategy<Value = String> {
+    proptest::collection::vec((word(), "[-_ ]{1,3}"), 1..6).prop_map(|words| {
+        let mut s = String::new();
+        for (i, (w, separator)) in words.iter().enumerate() {
+            if i > 0 {
+                s.push_str(separator);
+            }
+            s.push_str(w);
+        }
+        s
+    })
+}
+
+/// Returns phrases that may also have leading and trailing separators.
+fn wrapped_phrase() -> impl Strategy<Value = String> {
+    ("[-_ ]{0,3}", phrase(), "[-_ ]{0,3}").prop_map(|(head, s, tail)| head + &s + &tail)
+}
+
+/// Returns batches of phrases made of ASCII letters and separators, which
+/// have many word boundaries, of any printable ASCII characters, or of any
+/// characters. Phrases may be longer than one 64 byte block of masks used
+/// by the batch conversion.
+fn batch() -> impl Strategy<Value = Vec<String>> {
+    proptest::collection::vec("[a-cA-C0 _-]{0,140}|[ -~]{0,150}|\\PC*", 0..8)
+}
+
+fn words(s: &str) -> usize {
+    Case::Lower
+        .convert(s)
+        .split(' ')
+        .filter(|w| !w.is_empty())
+        .count()
+}
+
+fn separator(case: Case) -> Option<char> {
+    match case {
+        Case::Camel | Case::Pascal => None,
+        Case::Snake | Case::CamelSnake | Case::ScreamingSnake => Some('_'),
+        Case::Kebab | Case::CamelKebab | Case::ScreamingKebab => Some('-'),
+        Case::Lower | Case::Title | Case::Screaming => Some(' '),
+    }
+}
+
+proptest! {
+    #[test]
+    fn idempotence(case in case(), s in wrapped_phrase()) {
+        let converted = case.convert(&s);
+        prop_assert_eq!(case.convert(&converted), converted);
+    }
+
+    #[test]
+    fn commutation(from in case(), to in case(), s in phrase()) {
+        prop_assert_eq!(to.convert(&from.convert(&s)), to.convert(&s));
+    }
+
+    #[test]
+    fn family_commutation(family in 0..FAMILIES.len(), from in 0..3usize, to in 0..3usize, s in wrapped_phrase()) {
+        let family = FAMILIES[family];
+        let (from, to) = (family[from % family.len()], family[to % family.len()]);
+        prop_assert_eq!(to.convert(&from.convert(&s)), to.convert(&s));
+    }
+
+    #[test]
+    fn word_count(case in case(), s in wrapped_phrase()) {
+        prop_assert_eq!(words(&case.convert(&s)), words(&s));
+    }
+
+    #[test]
+    fn matches_converted(case in case(), s in wrapped_phrase()) {
+        prop_assert!(case.matches(&case.convert(&s)));
+    }
+
+    #[test]
+    fn no_double_separators(case in case(), s in "\\PC*") {
+        let converted = case.convert(&s);
+        if let Some(separator) = separator(case) {
+            let double = [separator, separator].iter().collect::<String>();
+            prop_assert!(!converted.trim_matches(separator).contains(&double), "{:?}", converted);
+        }
+    }
+
+    #[test]
+    fn convert_many_equals_convert(case in case(), phrases in batch()) {
+        let phrases: Vec<&str> = phrases.iter().map(String::as_str).collect();
+        let expected: Vec<String> = phrases.iter().map(|s| case.convert(s)).collect();
+        prop_assert_eq!(casbab::convert_many(case, &phrases), expected);
+    }
+
+    #[test]
+    fn reversible_round_trip(case in case(), s in "\\PC*") {
+        let (converted, hint) = reversible::convert(case, &s);
+        prop_assert_eq!(reversible::restore(case, &converted, &hint), Ok(s));
+    }
+}
+
+#[test]
+fn counterexamples() {
+    let cases = [
+        (Case::ScreamingKebab, "0a", "0A", "0-A"),
+        (Case::ScreamingKebab, "aa0", "AA0", "A-A0"),
+        (Case::Pascal, "a b", "AB", "Ab"),
+        (Case::Camel, "x 2fa", "x2fa", "x2fa"),
+        (Case::Snake, "ÀℋA", "àℋa", "à_ℋa"),
+        (Case::Camel, "ØΣ", "øς", "øς"),
+    ];
+    for (case, s, converted, twice) in cases {
+        assert_eq!(case.convert(s), converted, "{case:?} {s}");
+        assert_eq!(case.convert(converted), twice, "{case:?} {converted}");
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+use casbab::{
+    reversible::{convert, restore, HintError},
+    Case,
+};
+
+#[test]
+fn convert_test() {
+    let cases = [
+        (Case::Snake, "HTTPServer", "http_server", "ut"),
+        (Case::Snake, "Customer ID", "customer_id", "t.u"),
+        (Case::Snake, "__private_key", "__private_key", "__l_l"),
+        (Case::Kebab, "__private_key__", "private-key", "__l_l__"),
+        (Case::Camel, "camel-snake_kebab", "camelSnakeKebab", "l-l_l"),
+        (Case::Pascal, "x 2fa", "X2fa", "1l.3l"),
+        (Case::Lower, "", "", ""),
+        (Case::Snake, "--", "", "--"),
+        (Case::Screaming, "Straße", "STRASSE", "x7:Straße"),
+    ];
+    for (case, s, converted, hint) in cases {
+        assert_eq!(
+            convert(case, s),
+            (converted.to_string(), hint.to_string()),
+            "{case:?} {s}"
+        );
+    }
+}
+
+#[test]
+fn round_trip_test() {
+    let phrases = [
+        "HTTPServer",
+        "camelSNAKEKebab",
+        "__camel_snake_kebab__",
+        "--Camel-Snake-Kebab",
+        "  title  Case ",
+        "x 2fa code",
+        "v2Api_URL-path",
+        "Ово је Brave NewСвет",
+        "Straße und GRÖẞE",
+        "İstanbul",
+        "_",
+        "",
+    ];
+    for case in Case::ALL {
+        for s in phrases {
+            let (converted, hint) = convert(case, s);
+            assert_eq!(converted, case.convert(s));
+            assert_eq!(
+                restore(case, &converted, &hint).as_deref(),
+                Ok(s),
+                "{case:?} {s} {converted} {hint}"
+            );
+        }
+    }
+}
+
+#[test]
+fn restore_errors_test() {
+    let cases = [
+        (Case::Snake, "http_server", "u"),
+        (Case::Snake, "http_server", "utu"),
+        (Case::Snake, "http_server", "uq"),
+        (Case::Snake, "http_server", "u_x9:a"),
+        (Case::Camel, "httpServer", "4l"),
+        (Case::Camel, "httpServer", "4l7t"),
+        (Case::Camel, "httpServer", "99999999999999999999999l6t"),
+    ];
+    for (case, converted, hint) in cases {
+        assert_eq!(restore(case, converted, hint), Err(HintError), "{hint}");
+    }
+}
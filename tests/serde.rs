@@ -0,0 +1,132 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use casbab::serde::{Camel, Kebab, Renamed, ScreamingSnake};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Server {
+    http_server_url: String,
+    max_connections: u32,
+    tls: Option<Tls>,
+    listeners: Vec<Listener>,
+    labels: BTreeMap<String, String>,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Tls {
+    cert_file: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Listener {
+    bind_address: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Standalone,
+    Cluster { node_count: u8 },
+}
+
+fn server() -> Server {
+    Server {
+        http_server_url: "https://example.com".to_string(),
+        max_connections: 10,
+        tls: Some(Tls {
+            cert_file: "cert.pem".to_string(),
+        }),
+        listeners: vec![Listener {
+            bind_address: "[::]:80".to_string(),
+        }],
+        labels: BTreeMap::from([("app_name".to_string(), "web".to_string())]),
+        mode: Mode::Cluster { node_count: 3 },
+    }
+}
+
+#[test]
+fn renamed_test() {
+    let json = serde_json::to_string(&Renamed::<Camel, _>::new(server())).unwrap();
+    assert_eq!(
+        json,
+        r#"{"httpServerUrl":"https://example.com","maxConnections":10,"tls":{"certFile":"cert.pem"},"listeners":[{"bindAddress":"[::]:80"}],"labels":{"appName":"web"},"mode":{"Cluster":{"nodeCount":3}}}"#
+    );
+
+    let got: Renamed<Camel, Server> = serde_json::from_str(&json).unwrap();
+    let mut want = server();
+    // Map keys are not converted back.
+    want.labels = BTreeMap::from([("appName".to_string(), "web".to_string())]);
+    assert_eq!(got.into_inner(), want);
+}
+
+#[test]
+fn renamed_acronym_test() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[allow(non_snake_case)]
+    struct Acronyms {
+        CAMELSnakeKebab: u8,
+    }
+
+    let json =
+        serde_json::to_string(&Renamed::<Kebab, _>::new(Acronyms { CAMELSnakeKebab: 1 })).unwrap();
+    assert_eq!(json, r#"{"camel-snake-kebab":1}"#);
+
+    let got: Renamed<Kebab, Acronyms> = serde_json::from_str(&json).unwrap();
+    assert_eq!(*got, Acronyms { CAMELSnakeKebab: 1 });
+}
+
+#[test]
+fn renamed_unknown_field_test() {
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Strict {
+        page_size: u8,
+    }
+
+    let got: Renamed<Camel, Strict> = serde_json::from_str(r#"{"pageSize":1}"#).unwrap();
+    assert_eq!(*got, Strict { page_size: 1 });
+
+    let err = serde_json::from_str::<Renamed<Camel, Strict>>(r#"{"page_size":1}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown field `page_size`"));
+}
+
+#[test]
+fn with_test() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Env {
+        #[serde(with = "casbab::serde::screaming_snake")]
+        vars: Vars,
+        other_field: u8,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Vars {
+        database_url: String,
+    }
+
+    let env = Env {
+        vars: Vars {
+            database_url: "postgres://".to_string(),
+        },
+        other_field: 2,
+    };
+    let json = serde_json::to_string(&env).unwrap();
+    assert_eq!(
+        json,
+        r#"{"vars":{"DATABASE_URL":"postgres://"},"other_field":2}"#
+    );
+    assert_eq!(serde_json::from_str::<Env>(&json).unwrap(), env);
+
+    let json = serde_json::to_string(&Renamed::<ScreamingSnake, _>::new(Vars {
+        database_url: "postgres://".to_string(),
+    }))
+    .unwrap();
+    assert_eq!(json, r#"{"DATABASE_URL":"postgres://"}"#);
+}
@@ -0,0 +1,150 @@
+
+using namespace System.Management.Automation
+using namespace System.Management.Automation.Language
+
+Register-ArgumentCompleter -Native -CommandName 'casbab' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $commandElements = $commandAst.CommandElements
+    $command = @(
+        'casbab'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value.StartsWith('-') -or
+                $element.Value -eq $wordToComplete) {
+                break
+        }
+        $element.Value
+    }) -join ';'
+
+    $completions = @(switch ($command) {
+        'casbab' {
+            [CompletionResult]::new('--keys', '--keys', [CompletionResultType]::ParameterName, 'Convert keys of structured documents instead of phrases. Documents are read from files provided as arguments or from Stdin.')
+            [CompletionResult]::new('--match', '--match', [CompletionResultType]::ParameterName, 'Convert only matches of the regular expression in text, leaving the rest of it unchanged. If the pattern has capture groups, only the first group is converted. Text is read from files provided as arguments or from Stdin, line by line, so matches do not span lines.')
+            [CompletionResult]::new('--only-style', '--only-style', [CompletionResultType]::ParameterName, 'Convert only identifiers written in dialect, and in no other one, in text, leaving the rest of it unchanged, including single words that are written in many dialects. Text is read from files provided as arguments or from Stdin.')
+            [CompletionResult]::new('--exclude', '--exclude', [CompletionResultType]::ParameterName, 'JSON Pointer of the value which keys are not converted.')
+            [CompletionResult]::new('--depth', '--depth', [CompletionResultType]::ParameterName, 'Maximal number of nested levels which keys are converted.')
+            [CompletionResult]::new('--non-utf8', '--non-utf8', [CompletionResultType]::ParameterName, 'Handling of lines of phrases or text that are not valid UTF-8. They either stop the conversion with an error, or are written unchanged. Documents with keys to convert must always be valid UTF-8.')
+            [CompletionResult]::new('--delimiter', '--delimiter', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with the string instead of new lines.')
+            [CompletionResult]::new('-0', '-0', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.')
+            [CompletionResult]::new('--null', '--null', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('completions', 'completions', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('man', 'man', [CompletionResultType]::ParameterValue, 'Generate the man page in the roff format')
+            [CompletionResult]::new('all', 'all', [CompletionResultType]::ParameterValue, 'Convert a phrase into all dialects')
+            [CompletionResult]::new('csv-headers', 'csv-headers', [CompletionResultType]::ParameterValue, 'Convert column names in the header row of a CSV document')
+            [CompletionResult]::new('detect', 'detect', [CompletionResultType]::ParameterValue, 'Detect the dialect of phrases')
+            [CompletionResult]::new('rename', 'rename', [CompletionResultType]::ParameterValue, 'Rename files and directories')
+            [CompletionResult]::new('rewrite', 'rewrite', [CompletionResultType]::ParameterValue, 'Convert identifiers in text files in place')
+            [CompletionResult]::new('env', 'env', [CompletionResultType]::ParameterValue, 'Convert configuration paths into environment variable names')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'casbab;completions' {
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'casbab;man' {
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'casbab;all' {
+            [CompletionResult]::new('--format', '--format', [CompletionResultType]::ParameterName, 'Output format.')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'casbab;csv-headers' {
+            [CompletionResult]::new('--delimiter', '--delimiter', [CompletionResultType]::ParameterName, 'Field delimiter.')
+            [CompletionResult]::new('--quote', '--quote', [CompletionResultType]::ParameterName, 'Quote character.')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'casbab;detect' {
+            [CompletionResult]::new('--expect', '--expect', [CompletionResultType]::ParameterName, 'Exit with an error if any phrase is not written in dialect.')
+            [CompletionResult]::new('--delimiter', '--delimiter', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with the string instead of new lines.')
+            [CompletionResult]::new('-0', '-0', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.')
+            [CompletionResult]::new('--null', '--null', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'casbab;rename' {
+            [CompletionResult]::new('-r', '-r', [CompletionResultType]::ParameterName, 'Also rename files and directories in directories.')
+            [CompletionResult]::new('--recursive', '--recursive', [CompletionResultType]::ParameterName, 'Also rename files and directories in directories.')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print renames without renaming files.')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help (see more with ''--help'')')
+            break
+        }
+        'casbab;rewrite' {
+            [CompletionResult]::new('--from', '--from', [CompletionResultType]::ParameterName, 'Convert only identifiers that are written in dialect and in no other one.')
+            [CompletionResult]::new('--to', '--to', [CompletionResultType]::ParameterName, 'Convert identifiers into dialect.')
+            [CompletionResult]::new('--pattern', '--pattern', [CompletionResultType]::ParameterName, 'Regular expression that matches identifiers. By default, identifiers are words of letters, digits and underscores, and also hyphens if converting from a kebab dialect. If the pattern has capture groups, only the first group is converted.')
+            [CompletionResult]::new('--dry-run', '--dry-run', [CompletionResultType]::ParameterName, 'Print the diff of changes instead of rewriting files.')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'casbab;env' {
+            [CompletionResult]::new('--prefix', '--prefix', [CompletionResultType]::ParameterName, 'Prefix of environment variable names.')
+            [CompletionResult]::new('--separator', '--separator', [CompletionResultType]::ParameterName, 'Separator between nested path segments in names.')
+            [CompletionResult]::new('--delimiters', '--delimiters', [CompletionResultType]::ParameterName, 'Characters that separate segments in paths.')
+            [CompletionResult]::new('--inverse', '--inverse', [CompletionResultType]::ParameterName, 'Convert names back into paths with segments in dialect.')
+            [CompletionResult]::new('--delimiter', '--delimiter', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with the string instead of new lines.')
+            [CompletionResult]::new('-0', '-0', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.')
+            [CompletionResult]::new('--null', '--null', [CompletionResultType]::ParameterName, 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.')
+            [CompletionResult]::new('-h', '-h', [CompletionResultType]::ParameterName, 'Print help')
+            [CompletionResult]::new('--help', '--help', [CompletionResultType]::ParameterName, 'Print help')
+            break
+        }
+        'casbab;help' {
+            [CompletionResult]::new('completions', 'completions', [CompletionResultType]::ParameterValue, 'Generate shell completions')
+            [CompletionResult]::new('man', 'man', [CompletionResultType]::ParameterValue, 'Generate the man page in the roff format')
+            [CompletionResult]::new('all', 'all', [CompletionResultType]::ParameterValue, 'Convert a phrase into all dialects')
+            [CompletionResult]::new('csv-headers', 'csv-headers', [CompletionResultType]::ParameterValue, 'Convert column names in the header row of a CSV document')
+            [CompletionResult]::new('detect', 'detect', [CompletionResultType]::ParameterValue, 'Detect the dialect of phrases')
+            [CompletionResult]::new('rename', 'rename', [CompletionResultType]::ParameterValue, 'Rename files and directories')
+            [CompletionResult]::new('rewrite', 'rewrite', [CompletionResultType]::ParameterValue, 'Convert identifiers in text files in place')
+            [CompletionResult]::new('env', 'env', [CompletionResultType]::ParameterValue, 'Convert configuration paths into environment variable names')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'casbab;help;completions' {
+            break
+        }
+        'casbab;help;man' {
+            break
+        }
+        'casbab;help;all' {
+            break
+        }
+        'casbab;help;csv-headers' {
+            break
+        }
+        'casbab;help;detect' {
+            break
+        }
+        'casbab;help;rename' {
+            break
+        }
+        'casbab;help;rewrite' {
+            break
+        }
+        'casbab;help;env' {
+            break
+        }
+        'casbab;help;help' {
+            break
+        }
+    })
+
+    $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
+        Sort-Object -Property ListItemText
+}
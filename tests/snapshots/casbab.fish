@@ -0,0 +1,134 @@
+# Print an optspec for argparse to handle cmd's options that are independent of any subcommand.
+function __fish_casbab_global_optspecs
+    string join \n keys= match= only-style= exclude= depth= non-utf8= 0/null delimiter= h/help
+end
+
+function __fish_casbab_needs_command
+    # Figure out if the current invocation already has a command.
+    set -l cmd (commandline -opc)
+    set -e cmd[1]
+    argparse -s (__fish_casbab_global_optspecs) -- $cmd 2>/dev/null
+    or return
+    if set -q argv[1]
+        # Also print the command, so this can be used to figure out what it is.
+        echo $argv[1]
+        return 1
+    end
+    return 0
+end
+
+function __fish_casbab_using_subcommand
+    set -l cmd (__fish_casbab_needs_command)
+    test -z "$cmd"
+    and return 1
+    contains -- $cmd[1] $argv
+end
+
+complete -c casbab -n "__fish_casbab_needs_command" -l keys -d 'Convert keys of structured documents instead of phrases. Documents are read from files provided as arguments or from Stdin.' -r -f -a "json\t''
+toml\t''
+yaml\t''"
+complete -c casbab -n "__fish_casbab_needs_command" -l match -d 'Convert only matches of the regular expression in text, leaving the rest of it unchanged. If the pattern has capture groups, only the first group is converted. Text is read from files provided as arguments or from Stdin, line by line, so matches do not span lines.' -r
+complete -c casbab -n "__fish_casbab_needs_command" -l only-style -d 'Convert only identifiers written in dialect, and in no other one, in text, leaving the rest of it unchanged, including single words that are written in many dialects. Text is read from files provided as arguments or from Stdin.' -r -f -a "camel\t''
+pascal\t''
+snake\t''
+camel-snake\t''
+screaming-snake\t''
+kebab\t''
+camel-kebab\t''
+screaming-kebab\t''
+lower\t''
+title\t''
+screaming\t''"
+complete -c casbab -n "__fish_casbab_needs_command" -l exclude -d 'JSON Pointer of the value which keys are not converted.' -r
+complete -c casbab -n "__fish_casbab_needs_command" -l depth -d 'Maximal number of nested levels which keys are converted.' -r
+complete -c casbab -n "__fish_casbab_needs_command" -l non-utf8 -d 'Handling of lines of phrases or text that are not valid UTF-8. They either stop the conversion with an error, or are written unchanged. Documents with keys to convert must always be valid UTF-8.' -r -f -a "error\t''
+pass\t''"
+complete -c casbab -n "__fish_casbab_needs_command" -l delimiter -d 'Delimit phrases and output records with the string instead of new lines.' -r
+complete -c casbab -n "__fish_casbab_needs_command" -s 0 -l null -d 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.'
+complete -c casbab -n "__fish_casbab_needs_command" -s h -l help -d 'Print help'
+complete -c casbab -n "__fish_casbab_needs_command" -a "completions" -d 'Generate shell completions'
+complete -c casbab -n "__fish_casbab_needs_command" -a "man" -d 'Generate the man page in the roff format'
+complete -c casbab -n "__fish_casbab_needs_command" -a "all" -d 'Convert a phrase into all dialects'
+complete -c casbab -n "__fish_casbab_needs_command" -a "csv-headers" -d 'Convert column names in the header row of a CSV document'
+complete -c casbab -n "__fish_casbab_needs_command" -a "detect" -d 'Detect the dialect of phrases'
+complete -c casbab -n "__fish_casbab_needs_command" -a "rename" -d 'Rename files and directories'
+complete -c casbab -n "__fish_casbab_needs_command" -a "rewrite" -d 'Convert identifiers in text files in place'
+complete -c casbab -n "__fish_casbab_needs_command" -a "env" -d 'Convert configuration paths into environment variable names'
+complete -c casbab -n "__fish_casbab_needs_command" -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c casbab -n "__fish_casbab_using_subcommand completions" -s h -l help -d 'Print help'
+complete -c casbab -n "__fish_casbab_using_subcommand man" -s h -l help -d 'Print help'
+complete -c casbab -n "__fish_casbab_using_subcommand all" -l format -d 'Output format.' -r -f -a "table\t''
+json\t''
+tsv\t''"
+complete -c casbab -n "__fish_casbab_using_subcommand all" -s h -l help -d 'Print help'
+complete -c casbab -n "__fish_casbab_using_subcommand csv-headers" -l delimiter -d 'Field delimiter.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand csv-headers" -l quote -d 'Quote character.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand csv-headers" -s h -l help -d 'Print help'
+complete -c casbab -n "__fish_casbab_using_subcommand detect" -l expect -d 'Exit with an error if any phrase is not written in dialect.' -r -f -a "camel\t''
+pascal\t''
+snake\t''
+camel-snake\t''
+screaming-snake\t''
+kebab\t''
+camel-kebab\t''
+screaming-kebab\t''
+lower\t''
+title\t''
+screaming\t''"
+complete -c casbab -n "__fish_casbab_using_subcommand detect" -l delimiter -d 'Delimit phrases and output records with the string instead of new lines.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand detect" -s 0 -l null -d 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.'
+complete -c casbab -n "__fish_casbab_using_subcommand detect" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c casbab -n "__fish_casbab_using_subcommand rename" -s r -l recursive -d 'Also rename files and directories in directories.'
+complete -c casbab -n "__fish_casbab_using_subcommand rename" -l dry-run -d 'Print renames without renaming files.'
+complete -c casbab -n "__fish_casbab_using_subcommand rename" -s h -l help -d 'Print help (see more with \'--help\')'
+complete -c casbab -n "__fish_casbab_using_subcommand rewrite" -l from -d 'Convert only identifiers that are written in dialect and in no other one.' -r -f -a "camel\t''
+pascal\t''
+snake\t''
+camel-snake\t''
+screaming-snake\t''
+kebab\t''
+camel-kebab\t''
+screaming-kebab\t''
+lower\t''
+title\t''
+screaming\t''"
+complete -c casbab -n "__fish_casbab_using_subcommand rewrite" -l to -d 'Convert identifiers into dialect.' -r -f -a "camel\t''
+pascal\t''
+snake\t''
+camel-snake\t''
+screaming-snake\t''
+kebab\t''
+camel-kebab\t''
+screaming-kebab\t''
+lower\t''
+title\t''
+screaming\t''"
+complete -c casbab -n "__fish_casbab_using_subcommand rewrite" -l pattern -d 'Regular expression that matches identifiers. By default, identifiers are words of letters, digits and underscores, and also hyphens if converting from a kebab dialect. If the pattern has capture groups, only the first group is converted.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand rewrite" -l dry-run -d 'Print the diff of changes instead of rewriting files.'
+complete -c casbab -n "__fish_casbab_using_subcommand rewrite" -s h -l help -d 'Print help'
+complete -c casbab -n "__fish_casbab_using_subcommand env" -l prefix -d 'Prefix of environment variable names.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand env" -l separator -d 'Separator between nested path segments in names.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand env" -l delimiters -d 'Characters that separate segments in paths.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand env" -l inverse -d 'Convert names back into paths with segments in dialect.' -r -f -a "camel\t''
+pascal\t''
+snake\t''
+camel-snake\t''
+screaming-snake\t''
+kebab\t''
+camel-kebab\t''
+screaming-kebab\t''
+lower\t''
+title\t''
+screaming\t''"
+complete -c casbab -n "__fish_casbab_using_subcommand env" -l delimiter -d 'Delimit phrases and output records with the string instead of new lines.' -r
+complete -c casbab -n "__fish_casbab_using_subcommand env" -s 0 -l null -d 'Delimit phrases and output records with NUL characters instead of new lines, for example to read paths from `find -print0` or to pass them to `xargs -0`.'
+complete -c casbab -n "__fish_casbab_using_subcommand env" -s h -l help -d 'Print help'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "completions" -d 'Generate shell completions'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "man" -d 'Generate the man page in the roff format'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "all" -d 'Convert a phrase into all dialects'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "csv-headers" -d 'Convert column names in the header row of a CSV document'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "detect" -d 'Detect the dialect of phrases'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "rename" -d 'Rename files and directories'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "rewrite" -d 'Convert identifiers in text files in place'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "env" -d 'Convert configuration paths into environment variable names'
+complete -c casbab -n "__fish_casbab_using_subcommand help; and not __fish_seen_subcommand_from completions man all csv-headers detect rename rewrite env help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
@@ -3,8 +3,6 @@
 // Use of this source code is governed by a BSD-style
 // license that can be found in the LICENSE file.
 
-use casbab;
-
 struct Case {
     input: Vec<String>,
     camel: String,
@@ -209,3 +207,246 @@ fn casbab_test() {
         }
     }
 }
+
+#[test]
+fn case_test() {
+    let input = "xCAMELSnakeKebab_screaming pascal XXX";
+    assert_eq!(casbab::Case::Camel.convert(input), casbab::camel(input));
+    assert_eq!(casbab::Case::Pascal.convert(input), casbab::pascal(input));
+    assert_eq!(casbab::Case::Snake.convert(input), casbab::snake(input));
+    assert_eq!(
+        casbab::Case::CamelSnake.convert(input),
+        casbab::camel_snake(input)
+    );
+    assert_eq!(
+        casbab::Case::ScreamingSnake.convert(input),
+        casbab::screaming_snake(input)
+    );
+    assert_eq!(casbab::Case::Kebab.convert(input), casbab::kebab(input));
+    assert_eq!(
+        casbab::Case::CamelKebab.convert(input),
+        casbab::camel_kebab(input)
+    );
+    assert_eq!(
+        casbab::Case::ScreamingKebab.convert(input),
+        casbab::screaming_kebab(input)
+    );
+    assert_eq!(casbab::Case::Lower.convert(input), casbab::lower(input));
+    assert_eq!(casbab::Case::Title.convert(input), casbab::title(input));
+    assert_eq!(
+        casbab::Case::Screaming.convert(input),
+        casbab::screaming(input)
+    );
+}
+
+#[test]
+fn case_names_test() {
+    for case in casbab::Case::ALL {
+        assert_eq!(case.name(), casbab::kebab(&format!("{case:?}")));
+        assert_eq!(case.name().parse(), Ok(case));
+    }
+    assert_eq!(
+        "title ".parse::<casbab::Case>(),
+        Err(casbab::UnknownNameError::new("title "))
+    );
+}
+
+#[test]
+fn convert_many_test() {
+    let phrases = ["CustomerID", "order-date", "", "Ово је Brave NewСвет"];
+    let expected = ["customer_id", "order_date", "", "ово_је_brave_new_свет"];
+
+    assert_eq!(
+        casbab::convert_many(casbab::Case::Snake, &phrases),
+        expected
+    );
+
+    let mut buf = "prefix".to_string();
+    let mut offsets = Vec::new();
+    casbab::convert_many_into(casbab::Case::Snake, &phrases, &mut buf, &mut offsets);
+    let mut start = "prefix".len();
+    for (i, end) in offsets.into_iter().enumerate() {
+        assert_eq!(&buf[start..end], expected[i]);
+        start = end;
+    }
+    assert_eq!(start, buf.len());
+}
+
+#[test]
+fn display_test() {
+    use std::fmt::Write;
+
+    let input = "xCAMELSnakeKebab_screaming pascal XXX";
+    assert_eq!(casbab::Camel(input).to_string(), casbab::camel(input));
+    assert_eq!(casbab::Pascal(input).to_string(), casbab::pascal(input));
+    assert_eq!(casbab::Snake(input).to_string(), casbab::snake(input));
+    assert_eq!(
+        casbab::CamelSnake(input).to_string(),
+        casbab::camel_snake(input)
+    );
+    assert_eq!(
+        casbab::ScreamingSnake(input).to_string(),
+        casbab::screaming_snake(input)
+    );
+    assert_eq!(casbab::Kebab(input).to_string(), casbab::kebab(input));
+    assert_eq!(
+        casbab::CamelKebab(input).to_string(),
+        casbab::camel_kebab(input)
+    );
+    assert_eq!(
+        casbab::ScreamingKebab(input).to_string(),
+        casbab::screaming_kebab(input)
+    );
+    assert_eq!(casbab::Lower(input).to_string(), casbab::lower(input));
+    assert_eq!(casbab::Title(input).to_string(), casbab::title(input));
+    assert_eq!(
+        casbab::Screaming(input).to_string(),
+        casbab::screaming(input)
+    );
+
+    let mut code = String::new();
+    write!(
+        code,
+        "const {}: &str = \"{}\";",
+        casbab::Case::ScreamingSnake.display("httpServer"),
+        casbab::Case::Kebab.display("Ово је Brave NewСвет"),
+    )
+    .unwrap();
+    assert_eq!(code, "const HTTP_SERVER: &str = \"ово-је-brave-new-свет\";");
+}
+
+#[test]
+fn matches_test() {
+    use casbab::Case;
+
+    assert!(Case::Camel.matches("camelSnakeKebab"));
+    assert!(Case::Pascal.matches("CamelSnakeKebab"));
+    assert!(Case::Snake.matches("__camel_snake_kebab__"));
+    assert!(Case::ScreamingKebab.matches("CAMEL-SNAKE-KEBAB"));
+    assert!(Case::Title.matches("Ово Је Brave New Свет"));
+    assert!(Case::Lower.matches(""));
+
+    assert!(!Case::Camel.matches("CamelSnakeKebab"));
+    assert!(!Case::Snake.matches("camel_snake_kebab_x-"));
+    assert!(!Case::Snake.matches("camel__snake"));
+    assert!(!Case::Kebab.matches("camel-snake-kebab "));
+    assert!(!Case::Screaming.matches("CAMEL SNAKE KEBAb"));
+}
+
+#[test]
+fn ext_test() {
+    use casbab::{CasbabExt, Case};
+    use std::borrow::Cow;
+
+    let input = "xCAMELSnakeKebab_screaming pascal XXX";
+    assert_eq!(input.to_camel(), casbab::camel(input));
+    assert_eq!(input.to_pascal(), casbab::pascal(input));
+    assert_eq!(input.to_snake(), casbab::snake(input));
+    assert_eq!(input.to_camel_snake(), casbab::camel_snake(input));
+    assert_eq!(input.to_screaming_snake(), casbab::screaming_snake(input));
+    assert_eq!(input.to_kebab(), casbab::kebab(input));
+    assert_eq!(input.to_camel_kebab(), casbab::camel_kebab(input));
+    assert_eq!(input.to_screaming_kebab(), casbab::screaming_kebab(input));
+    assert_eq!(input.to_lower(), casbab::lower(input));
+    assert_eq!(input.to_title(), casbab::title(input));
+    assert_eq!(input.to_screaming(), casbab::screaming(input));
+
+    let owned = input.to_string();
+    assert_eq!(owned.to_case(Case::Kebab), casbab::kebab(input));
+    let cow: Cow<str> = Cow::Borrowed(input);
+    assert_eq!(cow.to_case(Case::Kebab), casbab::kebab(input));
+
+    assert!("http_server".is_case(Case::Snake));
+    assert!(!"httpServer".is_case(Case::Snake));
+    assert!(String::from("httpServer").is_case(Case::Camel));
+
+    fn keys<T: AsRef<str>>(keys: &[T]) -> Vec<String> {
+        keys.iter().map(|k| k.to_screaming_snake()).collect()
+    }
+    assert_eq!(keys(&["a.b", "maxSize"]), ["A.B", "MAX_SIZE"]);
+}
+
+#[test]
+fn lit_test() {
+    macro_rules! assert_lit {
+        ($($input:literal),* $(,)?) => {
+            $(
+                assert_eq!(casbab::camel_lit!($input), casbab::camel($input));
+                assert_eq!(casbab::pascal_lit!($input), casbab::pascal($input));
+                assert_eq!(casbab::snake_lit!($input), casbab::snake($input));
+                assert_eq!(casbab::camel_snake_lit!($input), casbab::camel_snake($input));
+                assert_eq!(casbab::screaming_snake_lit!($input), casbab::screaming_snake($input));
+                assert_eq!(casbab::kebab_lit!($input), casbab::kebab($input));
+                assert_eq!(casbab::camel_kebab_lit!($input), casbab::camel_kebab($input));
+                assert_eq!(casbab::screaming_kebab_lit!($input), casbab::screaming_kebab($input));
+                assert_eq!(casbab::lower_lit!($input), casbab::lower($input));
+                assert_eq!(casbab::title_lit!($input), casbab::title($input));
+                assert_eq!(casbab::screaming_lit!($input), casbab::screaming($input));
+            )*
+        };
+    }
+
+    assert_lit!(
+        "camelSnakeKebab",
+        "CAMELSnakeKebab",
+        "camel_ -snake_kebab",
+        "   camelSnakeKEBAB",
+        "__ camel-snake_kebab__ _",
+        "--CAMELSnake_kebab",
+        "-CAMEL   Snake_kebab ----",
+        "xCamelXXSnakeXXXKebab",
+        "X-CAMEL-XX_SNAKE-XXX-KEBAB",
+        "HttpServerURL",
+        "v2Api_3x",
+        "",
+        "     ",
+        "___",
+    );
+
+    const NAME: &str = casbab::snake_lit!("HttpServerURL");
+    assert_eq!(NAME, "http_server_url");
+}
+
+#[test]
+fn detect_test() {
+    use casbab::Case;
+
+    let cases: [(&str, &[Case]); 8] = [
+        ("http_server", &[Case::Snake]),
+        ("HttpServer", &[Case::Pascal]),
+        ("HTTP-SERVER", &[Case::ScreamingKebab]),
+        ("Http Server", &[Case::Title]),
+        (
+            "server",
+            &[Case::Camel, Case::Snake, Case::Kebab, Case::Lower],
+        ),
+        (
+            "SERVER",
+            &[Case::ScreamingSnake, Case::ScreamingKebab, Case::Screaming],
+        ),
+        ("http_Server", &[]),
+        ("HTTPServer", &[]),
+    ];
+    for (input, detected) in cases {
+        assert_eq!(casbab::detect(input), detected, "{input}");
+    }
+}
+
+#[test]
+fn words_test() {
+    let cases: [(&str, &[&str]); 7] = [
+        ("camelSNAKE_kebab", &["camel", "SNAKE", "kebab"]),
+        ("__ HTTPServer--URL __", &["HTTP", "Server", "URL"]),
+        ("v2Api", &["v2", "Api"]),
+        (
+            "Ово је Brave NewСвет",
+            &["Ово", "је", "Brave", "New", "Свет"],
+        ),
+        ("", &[]),
+        (" -_", &[]),
+        ("x", &["x"]),
+    ];
+    for (input, words) in cases {
+        assert_eq!(casbab::words(input).collect::<Vec<_>>(), words, "{input}");
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+#![cfg(feature = "toml")]
+
+use casbab::{toml::convert_str, Case};
+
+#[test]
+fn convert_str_test() {
+    let input = r#"# Package configuration.
+package_name = "casbab" # trailing comment
+
+[build_settings]
+# Comment above the key.
+opt_level = 3
+target_dirs = [{ dir_name = "a" }, { dir_name = "b" }]
+dotted.key_name = true
+
+[[bin_targets]]
+bin_name = "casbab"
+
+[[bin_targets]]
+bin_name = "other"
+
+[metadata]
+free_form = { any_key = 1 }
+"#;
+
+    assert_eq!(
+        convert_str(input, Case::Camel, None, &["/metadata"]).unwrap(),
+        r#"# Package configuration.
+packageName = "casbab" # trailing comment
+
+[buildSettings]
+# Comment above the key.
+optLevel = 3
+targetDirs = [{ dirName = "a" }, { dirName = "b" }]
+dotted.keyName = true
+
+[[binTargets]]
+binName = "casbab"
+
+[[binTargets]]
+binName = "other"
+
+[metadata]
+free_form = { any_key = 1 }
+"#
+    );
+}
+
+#[test]
+fn convert_str_quoted_keys_test() {
+    assert_eq!(
+        convert_str("\"key name\" = 1\nplain_key = 2\n", Case::Lower, None, &[]).unwrap(),
+        "\"key name\" = 1\n\"plain key\" = 2\n"
+    );
+}
+
+#[test]
+fn convert_str_depth_limit_test() {
+    assert_eq!(
+        convert_str("top_key = { nested_key = 1 }\n", Case::Kebab, Some(1), &[]).unwrap(),
+        "top-key = { nested_key = 1 }\n"
+    );
+}
+
+#[test]
+fn convert_str_errors_test() {
+    let err = convert_str(
+        "[table_name]\nfoo_bar = 1\nfooBar = 2\n",
+        Case::Snake,
+        None,
+        &[],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "keys `foo_bar` and `fooBar` at `/table_name` are both converted to `foo_bar`"
+    );
+
+    let err = convert_str("x = [", Case::Snake, None, &[]).unwrap_err();
+    assert!(matches!(err, casbab::toml::Error::Parse(_)));
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2024, Janoš Guljaš <janos@resenje.org>
+// All rights reserved.
+// Use of this source code is governed by a BSD-style
+// license that can be found in the LICENSE file.
+
+#![cfg(feature = "yaml")]
+
+use casbab::{yaml::convert_str, Case};
+
+#[test]
+fn convert_str_test() {
+    let input = "replica_count: 2
+image_config:
+  pull_policy: IfNotPresent
+  tag_name: latest
+extra_env:
+- env_name: A
+  env_value: '1'
+pod_annotations:
+  example.com/some_key: value
+1: numeric key
+tagged_value: !custom
+  inner_key: 1
+";
+
+    assert_eq!(
+        convert_str(
+            input,
+            Case::Camel,
+            None,
+            &["/podAnnotations", "pod_annotations"]
+        )
+        .unwrap(),
+        "replicaCount: 2
+imageConfig:
+  pullPolicy: IfNotPresent
+  tagName: latest
+extraEnv:
+- envName: A
+  envValue: '1'
+podAnnotations:
+  example.com/some_key: value
+1: numeric key
+taggedValue: !custom
+  innerKey: 1
+"
+    );
+}
+
+#[test]
+fn convert_str_depth_limit_test() {
+    assert_eq!(
+        convert_str(
+            "top_key:\n  nested_key: 1\n",
+            Case::ScreamingSnake,
+            Some(1),
+            &[]
+        )
+        .unwrap(),
+        "TOP_KEY:\n  nested_key: 1\n"
+    );
+}
+
+#[test]
+fn convert_str_errors_test() {
+    let err = convert_str(
+        "list:\n- foo-bar: 1\n  foo_bar: 2\n",
+        Case::Kebab,
+        None,
+        &[],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "keys `foo-bar` and `foo_bar` at `/list/0` are both converted to `foo-bar`"
+    );
+
+    let err = convert_str("a: [", Case::Kebab, None, &[]).unwrap_err();
+    assert!(matches!(err, casbab::yaml::Error::Parse(_)));
+}
+
+#[test]
+fn convert_str_stream_test() {
+    let input = "---
+api_version: v1
+kind: Service
+---
+api_version: apps/v1
+kind: Deployment
+spec:
+  replica_count: 2
+";
+
+    assert_eq!(
+        convert_str(input, Case::Camel, None, &[]).unwrap(),
+        "apiVersion: v1
+kind: Service
+---
+apiVersion: apps/v1
+kind: Deployment
+spec:
+  replicaCount: 2
+"
+    );
+
+    let err = convert_str("a_b: 1\n---\na: [", Case::Kebab, None, &[]).unwrap_err();
+    assert!(matches!(err, casbab::yaml::Error::Parse(_)));
+}